@@ -0,0 +1,161 @@
+//! Step-by-step configuration of a display before bringing it up.
+
+use hal::blocking::delay::DelayMs;
+use hal::digital::v2::{InputPin, OutputPin};
+
+use interface::Interface;
+use {
+    cmds, ByteOrder, ColorDepth, DisplayColor, Error, PinError, ReadyPolarity, ReadySource,
+    Rotation, SpiError, Timing, PLL_LOCK_TIMEOUT_MS, RA8875,
+};
+
+type BuildResult<SPI, P, O> = Result<RA8875<SPI, P, O>, Error<SpiError<SPI>, PinError<P>>>;
+
+/// Collects display configuration and runs the power-on sequence in the right order.
+///
+/// `build` resets the chip, sets up the PLL and waits for it to lock, initializes the panel
+/// timing, turns the display on, enables the panel through GPIOX, and then applies the optional
+/// rotation, text colors, and text scale.
+pub struct RA8875Builder<SPI, P, O> {
+    spi: SPI,
+    ready: P,
+    rst: O,
+    dims: (u32, u32),
+    rotation: Rotation,
+    colors: Option<(u16, Option<u16>)>,
+    text_scale: Option<u8>,
+    pll: (u8, u8),
+    timing: Option<Timing>,
+    color_depth: ColorDepth,
+    byte_order: ByteOrder,
+    ready_source: ReadySource,
+    ready_limit: Option<u32>,
+}
+
+impl<SPI, P, O> RA8875Builder<SPI, P, O>
+where
+    SPI: Interface,
+    P: InputPin,
+    O: OutputPin<Error = PinError<P>>,
+{
+    /// Starts a configuration for an 800x480 panel with the default PLL settings.
+    pub fn new(spi: SPI, ready: P, rst: O) -> Self {
+        RA8875Builder {
+            spi,
+            ready,
+            rst,
+            dims: (800, 480),
+            rotation: Rotation::Rotate0,
+            colors: None,
+            text_scale: None,
+            pll: (cmds::PllC1::Div1 as u8 + 10, cmds::PllC2::Div4 as u8),
+            timing: None,
+            color_depth: ColorDepth::Bpp16,
+            byte_order: ByteOrder::HighFirst,
+            ready_source: ReadySource::Pin(ReadyPolarity::ActiveLow),
+            ready_limit: None,
+        }
+    }
+
+    /// Sets the panel dimensions in pixels.
+    pub fn dimensions(mut self, dims: (u32, u32)) -> Self {
+        self.dims = dims;
+        self
+    }
+
+    /// Sets the initial rotation of the drawing coordinate frame.
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets the default text foreground and background colors. A `bg_color` of `None` draws
+    /// text with a transparent background.
+    pub fn colors<C: DisplayColor>(mut self, fg_color: C, bg_color: Option<C>) -> Self {
+        self.colors = Some((fg_color.into_raw(), bg_color.map(C::into_raw)));
+        self
+    }
+
+    /// Sets the default text scale, as accepted by `set_text_scale`.
+    pub fn text_scale(mut self, scale: u8) -> Self {
+        self.text_scale = Some(scale);
+        self
+    }
+
+    /// Overrides the raw PLLC1 and PLLC2 register values written during setup.
+    pub fn pll(mut self, pllc1: u8, pllc2: u8) -> Self {
+        self.pll = (pllc1, pllc2);
+        self
+    }
+
+    /// Supplies panel timing for dimensions without a built-in timing table.
+    pub fn timing(mut self, timing: Timing) -> Self {
+        self.timing = Some(timing);
+        self
+    }
+
+    /// Sets the color depth of display memory.
+    pub fn color_depth(mut self, depth: ColorDepth) -> Self {
+        self.color_depth = depth;
+        self
+    }
+
+    /// Sets the order the bytes of each 16bpp pixel are sent in.
+    pub fn byte_order(mut self, order: ByteOrder) -> Self {
+        self.byte_order = order;
+        self
+    }
+
+    /// Selects how busy is detected, for boards with an inverted or unconnected `ready` pin.
+    pub fn ready_source(mut self, source: ReadySource) -> Self {
+        self.ready_source = source;
+        self
+    }
+
+    /// Caps how long the driver waits for the chip to become ready, as `set_ready_limit` does.
+    /// Applied before the reset, so a miswired `ready` pin makes `build` fail rather than hang.
+    pub fn ready_limit(mut self, limit: Option<u32>) -> Self {
+        self.ready_limit = limit;
+        self
+    }
+
+    /// Brings up the display and returns the configured driver.
+    ///
+    /// Dimensions without a built-in timing are rejected before the chip is touched, unless a
+    /// timing was supplied.
+    pub fn build<D: DelayMs<u8>>(self, delay: &mut D) -> BuildResult<SPI, P, O> {
+        let timing = match self.timing.or_else(|| Timing::for_dimensions(self.dims)) {
+            Some(timing) => timing,
+            None => return Err(Error::UnsupportedDimensions),
+        };
+
+        let mut display = RA8875::new(self.spi, self.dims, self.ready, self.rst);
+        display.set_ready_source(self.ready_source);
+        display.set_ready_limit(self.ready_limit);
+        display.set_byte_order(self.byte_order);
+        display.reset(delay)?;
+        display.write_pll(self.pll.0, self.pll.1)?;
+        // Let the PLL lock before clocking the rest of the configuration in.
+        display.wait_for_pll(delay, PLL_LOCK_TIMEOUT_MS)?;
+        display.init_with_timing(timing)?;
+        if self.color_depth != ColorDepth::Bpp16 {
+            display.set_color_depth(self.color_depth)?;
+        }
+        display.display_on(true)?;
+        display.gpiox(true)?;
+        display.set_rotation(self.rotation)?;
+
+        if self.colors.is_some() || self.text_scale.is_some() {
+            display.text_mode()?;
+            if let Some((fg_color, bg_color)) = self.colors {
+                display.set_colors(fg_color, bg_color)?;
+            }
+            if let Some(scale) = self.text_scale {
+                display.set_text_scale(scale)?;
+            }
+            display.graphics_mode()?;
+        }
+
+        Ok(display)
+    }
+}
@@ -0,0 +1,145 @@
+//! Off-screen composition of a frame in RAM.
+//!
+//! Drawing straight to the display shows every intermediate step, which tears on
+//! busy screens. A `Framebuffer` collects the draws instead and `flush` sends only
+//! the rectangle that changed, streamed as an image.
+
+use core::convert::Infallible;
+
+use embedded_graphics::{
+    pixelcolor::{IntoStorage, Rgb565},
+    prelude::*,
+    primitives::Rectangle,
+};
+use hal::digital::v2::{InputPin, OutputPin};
+
+use interface::Interface;
+use {Error, PinError, SpiError, RA8875};
+
+/// A `W` by `H` pixel RGB565 frame held in RAM.
+///
+/// The buffer takes `W * H * 2` bytes, so it is usually placed in a `static` rather than on
+/// the stack. Coordinates are the same as the display's drawing frame, with the buffer's
+/// top-left corner at the display origin.
+pub struct Framebuffer<const W: usize, const H: usize> {
+    pixels: [[u16; W]; H],
+    /// Inclusive bounds of the pixels changed since the last flush.
+    dirty: Option<(Point, Point)>,
+}
+
+impl<const W: usize, const H: usize> Framebuffer<W, H> {
+    /// Creates a black frame with nothing marked dirty.
+    pub const fn new() -> Self {
+        Framebuffer {
+            pixels: [[0; W]; H],
+            dirty: None,
+        }
+    }
+
+    /// Whether anything was drawn since the last flush.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.is_some()
+    }
+
+    /// Marks the whole frame for the next flush, for example after the display was drawn on
+    /// directly.
+    pub fn invalidate(&mut self) {
+        if W > 0 && H > 0 {
+            self.dirty = Some((Point::zero(), Point::new(W as i32 - 1, H as i32 - 1)));
+        }
+    }
+
+    /// Returns the color stored at `point`, or `None` if it lies outside the frame.
+    pub fn pixel(&self, point: Point) -> Option<u16> {
+        if self.contains(point) {
+            Some(self.pixels[point.y as usize][point.x as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Sends the dirty region to `display` and clears it.
+    ///
+    /// The region goes out with `draw_image_iter`, which handles the display's rotation, mode,
+    /// and active window.
+    pub fn flush<SPI, P, O>(
+        &mut self,
+        display: &mut RA8875<SPI, P, O>,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>>
+    where
+        SPI: Interface,
+        P: InputPin,
+        O: OutputPin,
+    {
+        let (top_left, bottom_right) = match self.dirty {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+        let size = (
+            (bottom_right.x - top_left.x + 1) as u16,
+            (bottom_right.y - top_left.y + 1) as u16,
+        );
+        let columns = top_left.x as usize..=bottom_right.x as usize;
+        let pixels = self.pixels[top_left.y as usize..=bottom_right.y as usize]
+            .iter()
+            .flat_map(|row| row[columns.clone()].iter().copied());
+        display.draw_image_iter((top_left.x as i16, top_left.y as i16), size, pixels)?;
+        self.dirty = None;
+        Ok(())
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.y >= 0 && (point.x as usize) < W && (point.y as usize) < H
+    }
+
+    fn mark_dirty(&mut self, point: Point) {
+        self.dirty = Some(match self.dirty {
+            Some((min, max)) => (min.component_min(point), max.component_max(point)),
+            None => (point, point),
+        });
+    }
+}
+
+impl<const W: usize, const H: usize> Default for Framebuffer<W, H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize, const H: usize> OriginDimensions for Framebuffer<W, H> {
+    fn size(&self) -> Size {
+        Size::new(W as u32, H as u32)
+    }
+}
+
+impl<const W: usize, const H: usize> DrawTarget for Framebuffer<W, H> {
+    type Color = Rgb565;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if self.contains(point) {
+                self.pixels[point.y as usize][point.x as usize] = color.into_storage();
+                self.mark_dirty(point);
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.bounding_box());
+        if let Some(bottom_right) = area.bottom_right() {
+            let color = color.into_storage();
+            let columns = area.top_left.x as usize..=bottom_right.x as usize;
+            for row in &mut self.pixels[area.top_left.y as usize..=bottom_right.y as usize] {
+                row[columns.clone()].fill(color);
+            }
+            self.mark_dirty(area.top_left);
+            self.mark_dirty(bottom_right);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,461 @@
+//! Bus interfaces used to talk to the RA8875.
+//!
+//! Every RA8875 SPI transaction starts with a single command byte selecting
+//! between a command write, a data write, a data read, or a status read. The
+//! [`Interface`] trait hides how those transactions are framed on the bus so
+//! the driver itself doesn't have to care about chip-select handling.
+//!
+//! Chip-select is never held between calls into an [`Interface`]: the chip
+//! keeps the selected register and memory cursor while deselected, so other
+//! devices on a shared bus can be served between any two transactions. Within a
+//! streamed write, [`LegacySpi`], [`LegacyBlockingSpi`] and [`ExclusiveSpi`]
+//! hold chip-select for the whole run, while a `SpiDevice` releases it between
+//! chunks.
+
+#[cfg(feature = "legacy-spi")]
+use hal::blocking::spi::{Transfer, Write};
+#[cfg(feature = "legacy-spi")]
+use hal::digital::v2::OutputPin;
+#[cfg(feature = "legacy-spi")]
+use hal::spi::FullDuplex;
+use hal1::digital::OutputPin as OutputPin1;
+use hal1::spi::{Operation, SpiBus, SpiDevice};
+
+use Command;
+
+/// Size of the stack buffer used to batch streamed data into bus writes.
+const CHUNK_SIZE: usize = 64;
+
+/// A transport capable of issuing RA8875 command, data, and status cycles.
+pub trait Interface {
+    type Error;
+
+    /// Write a register address.
+    fn write_command(&mut self, command: u8) -> Result<(), Self::Error>;
+
+    /// Write a single data byte to the currently selected register.
+    fn write_data(&mut self, data: u8) -> Result<(), Self::Error>;
+
+    /// Read a single data byte from the currently selected register.
+    fn read_data(&mut self) -> Result<u8, Self::Error>;
+
+    /// Read the status register.
+    fn read_status(&mut self) -> Result<u8, Self::Error>;
+
+    /// Stream a run of data bytes to the currently selected register.
+    ///
+    /// This is used for memory writes, where the chip auto-increments its
+    /// write cursor after every pixel. Callers should supply whole pixels so
+    /// that implementations which split the run into several bus transfers
+    /// never split a pixel across them.
+    fn write_data_iter<I>(&mut self, data: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = u8>;
+}
+
+/// Any `embedded-hal` 1.0 `SpiDevice` can drive the RA8875 directly. The HAL
+/// owns chip-select, so the bus may be shared with other devices.
+///
+/// Streamed data is gathered into a 64 byte stack buffer, and each buffer is
+/// sent as its own data-write transaction, so chip-select is released between
+/// chunks. A `SpiDevice` transaction has to be given all of its data up front,
+/// which a stream of unknown length can't be without an allocator. Use
+/// [`ExclusiveSpi`] where chip-select must stay asserted for the whole run.
+impl<SPI: SpiDevice> Interface for SPI {
+    type Error = SPI::Error;
+
+    fn write_command(&mut self, command: u8) -> Result<(), Self::Error> {
+        self.write(&[Command::CmdWrite as u8, command])
+    }
+
+    fn write_data(&mut self, data: u8) -> Result<(), Self::Error> {
+        self.write(&[Command::DataWrite as u8, data])
+    }
+
+    fn read_data(&mut self) -> Result<u8, Self::Error> {
+        let mut buf = [0_u8];
+        self.transaction(&mut [
+            Operation::Write(&[Command::DataRead as u8]),
+            Operation::Read(&mut buf),
+        ])?;
+        Ok(buf[0])
+    }
+
+    fn read_status(&mut self) -> Result<u8, Self::Error> {
+        let mut buf = [0_u8];
+        self.transaction(&mut [
+            Operation::Write(&[Command::CmdRead as u8]),
+            Operation::Read(&mut buf),
+        ])?;
+        Ok(buf[0])
+    }
+
+    fn write_data_iter<I>(&mut self, data: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut buf = [0_u8; CHUNK_SIZE];
+        let mut len = 0;
+        for byte in data {
+            buf[len] = byte;
+            len += 1;
+            if len == CHUNK_SIZE {
+                self.transaction(&mut [
+                    Operation::Write(&[Command::DataWrite as u8]),
+                    Operation::Write(&buf),
+                ])?;
+                len = 0;
+            }
+        }
+        if len > 0 {
+            self.transaction(&mut [
+                Operation::Write(&[Command::DataWrite as u8]),
+                Operation::Write(&buf[..len]),
+            ])?;
+        }
+        Ok(())
+    }
+}
+
+/// Errors from a [`LegacySpi`] interface.
+#[cfg(feature = "legacy-spi")]
+#[derive(Debug)]
+pub enum LegacySpiError<SpiE, PinE> {
+    /// The SPI peripheral failed.
+    Spi(SpiE),
+    /// The chip-select pin failed.
+    Pin(PinE),
+}
+
+#[cfg(feature = "legacy-spi")]
+type LegacyResult<T, SPI, CS> =
+    Result<T, LegacySpiError<<SPI as FullDuplex<u8>>::Error, <CS as OutputPin>::Error>>;
+
+/// An `embedded-hal` 0.2 `FullDuplex` SPI peripheral paired with a manually
+/// toggled chip-select pin.
+///
+/// Every byte is sent and then its reply read back before the next one goes
+/// out. HALs whose `FullDuplex` doesn't pair sends and reads that way can
+/// return shifted reads; use [`LegacyBlockingSpi`] with those instead.
+#[cfg(feature = "legacy-spi")]
+pub struct LegacySpi<SPI, CS> {
+    pub spi: SPI,
+    pub cs: CS,
+}
+
+#[cfg(feature = "legacy-spi")]
+impl<SPI, CS> LegacySpi<SPI, CS>
+where
+    SPI: FullDuplex<u8>,
+    CS: OutputPin,
+{
+    pub fn new(spi: SPI, cs: CS) -> Self {
+        LegacySpi { spi, cs }
+    }
+
+    fn spi_send(&mut self, data: u8) -> LegacyResult<(), SPI, CS> {
+        block!(self.spi.send(data)).map_err(LegacySpiError::Spi)?;
+        block!(self.spi.read()).map_err(LegacySpiError::Spi)?; // Dummy read, toss the result.
+        Ok(())
+    }
+
+    fn spi_read(&mut self) -> LegacyResult<u8, SPI, CS> {
+        let dummy = 0_u8;
+        block!(self.spi.send(dummy)).map_err(LegacySpiError::Spi)?; // Dummy write for full duplex
+        let result = block!(self.spi.read()).map_err(LegacySpiError::Spi)?;
+        Ok(result)
+    }
+
+    fn select(&mut self) -> LegacyResult<(), SPI, CS> {
+        self.cs.set_low().map_err(LegacySpiError::Pin)
+    }
+
+    fn deselect(&mut self) -> LegacyResult<(), SPI, CS> {
+        self.cs.set_high().map_err(LegacySpiError::Pin)
+    }
+
+    /// Runs `cycle` with chip-select asserted, and deasserts it afterwards even if the
+    /// cycle failed part way.
+    fn framed<T, F>(&mut self, cycle: F) -> LegacyResult<T, SPI, CS>
+    where
+        F: FnOnce(&mut Self) -> LegacyResult<T, SPI, CS>,
+    {
+        self.select()?;
+        let result = cycle(self);
+        let deselected = self.deselect();
+        let value = result?;
+        deselected?;
+        Ok(value)
+    }
+
+    fn write_cycle(&mut self, command: Command, data: u8) -> LegacyResult<(), SPI, CS> {
+        self.framed(|bus| {
+            bus.spi_send(command as u8)?;
+            bus.spi_send(data)
+        })
+    }
+
+    fn read_cycle(&mut self, command: Command) -> LegacyResult<u8, SPI, CS> {
+        self.framed(|bus| {
+            bus.spi_send(command as u8)?;
+            bus.spi_read()
+        })
+    }
+}
+
+#[cfg(feature = "legacy-spi")]
+impl<SPI, CS> Interface for LegacySpi<SPI, CS>
+where
+    SPI: FullDuplex<u8>,
+    CS: OutputPin,
+{
+    type Error = LegacySpiError<SPI::Error, CS::Error>;
+
+    fn write_command(&mut self, command: u8) -> Result<(), Self::Error> {
+        self.write_cycle(Command::CmdWrite, command)
+    }
+
+    fn write_data(&mut self, data: u8) -> Result<(), Self::Error> {
+        self.write_cycle(Command::DataWrite, data)
+    }
+
+    fn read_data(&mut self) -> Result<u8, Self::Error> {
+        self.read_cycle(Command::DataRead)
+    }
+
+    fn read_status(&mut self) -> Result<u8, Self::Error> {
+        self.read_cycle(Command::CmdRead)
+    }
+
+    fn write_data_iter<I>(&mut self, data: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        self.framed(|bus| {
+            bus.spi_send(Command::DataWrite as u8)?;
+            for byte in data {
+                bus.spi_send(byte)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(feature = "legacy-spi")]
+type LegacyBlockingResult<T, SPI, CS> =
+    Result<T, LegacySpiError<<SPI as Transfer<u8>>::Error, <CS as OutputPin>::Error>>;
+
+/// An `embedded-hal` 0.2 blocking SPI peripheral paired with a manually
+/// toggled chip-select pin.
+///
+/// Reads go through `Transfer`, so the command byte and the byte clocked back
+/// are exchanged in a single full-duplex transfer and the HAL handles the
+/// framing. Writes and streamed data go through `Write`.
+#[cfg(feature = "legacy-spi")]
+pub struct LegacyBlockingSpi<SPI, CS> {
+    pub spi: SPI,
+    pub cs: CS,
+}
+
+#[cfg(feature = "legacy-spi")]
+impl<SPI, CS> LegacyBlockingSpi<SPI, CS>
+where
+    SPI: Transfer<u8> + Write<u8, Error = <SPI as Transfer<u8>>::Error>,
+    CS: OutputPin,
+{
+    pub fn new(spi: SPI, cs: CS) -> Self {
+        LegacyBlockingSpi { spi, cs }
+    }
+
+    fn select(&mut self) -> LegacyBlockingResult<(), SPI, CS> {
+        self.cs.set_low().map_err(LegacySpiError::Pin)
+    }
+
+    fn deselect(&mut self) -> LegacyBlockingResult<(), SPI, CS> {
+        self.cs.set_high().map_err(LegacySpiError::Pin)
+    }
+
+    /// Runs `cycle` with chip-select asserted, and deasserts it afterwards even if the
+    /// cycle failed part way.
+    fn framed<T, F>(&mut self, cycle: F) -> LegacyBlockingResult<T, SPI, CS>
+    where
+        F: FnOnce(&mut Self) -> LegacyBlockingResult<T, SPI, CS>,
+    {
+        self.select()?;
+        let result = cycle(self);
+        let deselected = self.deselect();
+        let value = result?;
+        deselected?;
+        Ok(value)
+    }
+
+    fn write_cycle(&mut self, command: Command, data: u8) -> LegacyBlockingResult<(), SPI, CS> {
+        self.framed(|bus| {
+            bus.spi
+                .write(&[command as u8, data])
+                .map_err(LegacySpiError::Spi)
+        })
+    }
+
+    fn read_cycle(&mut self, command: Command) -> LegacyBlockingResult<u8, SPI, CS> {
+        self.framed(|bus| {
+            let mut buf = [command as u8, 0];
+            Ok(bus.spi.transfer(&mut buf).map_err(LegacySpiError::Spi)?[1])
+        })
+    }
+}
+
+#[cfg(feature = "legacy-spi")]
+impl<SPI, CS> Interface for LegacyBlockingSpi<SPI, CS>
+where
+    SPI: Transfer<u8> + Write<u8, Error = <SPI as Transfer<u8>>::Error>,
+    CS: OutputPin,
+{
+    type Error = LegacySpiError<<SPI as Transfer<u8>>::Error, CS::Error>;
+
+    fn write_command(&mut self, command: u8) -> Result<(), Self::Error> {
+        self.write_cycle(Command::CmdWrite, command)
+    }
+
+    fn write_data(&mut self, data: u8) -> Result<(), Self::Error> {
+        self.write_cycle(Command::DataWrite, data)
+    }
+
+    fn read_data(&mut self) -> Result<u8, Self::Error> {
+        self.read_cycle(Command::DataRead)
+    }
+
+    fn read_status(&mut self) -> Result<u8, Self::Error> {
+        self.read_cycle(Command::CmdRead)
+    }
+
+    fn write_data_iter<I>(&mut self, data: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        self.framed(|bus| {
+            let mut buf = [0_u8; CHUNK_SIZE];
+            buf[0] = Command::DataWrite as u8;
+            let mut len = 1;
+            for byte in data {
+                buf[len] = byte;
+                len += 1;
+                if len == CHUNK_SIZE {
+                    bus.spi.write(&buf).map_err(LegacySpiError::Spi)?;
+                    len = 0;
+                }
+            }
+            if len > 0 {
+                bus.spi.write(&buf[..len]).map_err(LegacySpiError::Spi)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Errors from an [`ExclusiveSpi`] interface.
+#[derive(Debug)]
+pub enum ExclusiveSpiError<BusE, PinE> {
+    /// The SPI bus failed.
+    Spi(BusE),
+    /// The chip-select pin failed.
+    Pin(PinE),
+}
+
+type ExclusiveResult<T, BUS, CS> = Result<
+    T,
+    ExclusiveSpiError<
+        <BUS as hal1::spi::ErrorType>::Error,
+        <CS as hal1::digital::ErrorType>::Error,
+    >,
+>;
+
+/// An `embedded-hal` 1.0 `SpiBus` paired with a chip-select pin that the driver
+/// toggles itself.
+///
+/// Unlike a `SpiDevice`, this holds chip-select for the whole of a streamed
+/// write, and only deasserts it once the last byte has gone out. The bus isn't
+/// shared: nothing else may use it while the display owns it.
+pub struct ExclusiveSpi<BUS, CS> {
+    pub bus: BUS,
+    pub cs: CS,
+}
+
+impl<BUS, CS> ExclusiveSpi<BUS, CS>
+where
+    BUS: SpiBus,
+    CS: OutputPin1,
+{
+    pub fn new(bus: BUS, cs: CS) -> Self {
+        ExclusiveSpi { bus, cs }
+    }
+
+    /// Runs `cycle` with chip-select asserted, waits for the bus to finish, and deasserts
+    /// chip-select even if the cycle failed part way.
+    fn framed<T, F>(&mut self, cycle: F) -> ExclusiveResult<T, BUS, CS>
+    where
+        F: FnOnce(&mut BUS) -> Result<T, BUS::Error>,
+    {
+        self.cs.set_low().map_err(ExclusiveSpiError::Pin)?;
+        let result = cycle(&mut self.bus).and_then(|value| self.bus.flush().map(|()| value));
+        let deselected = self.cs.set_high();
+        let value = result.map_err(ExclusiveSpiError::Spi)?;
+        deselected.map_err(ExclusiveSpiError::Pin)?;
+        Ok(value)
+    }
+
+    fn read_cycle(&mut self, command: Command) -> ExclusiveResult<u8, BUS, CS> {
+        self.framed(|bus| {
+            let mut buf = [command as u8, 0];
+            bus.transfer_in_place(&mut buf)?;
+            Ok(buf[1])
+        })
+    }
+}
+
+impl<BUS, CS> Interface for ExclusiveSpi<BUS, CS>
+where
+    BUS: SpiBus,
+    CS: OutputPin1,
+{
+    type Error = ExclusiveSpiError<BUS::Error, CS::Error>;
+
+    fn write_command(&mut self, command: u8) -> Result<(), Self::Error> {
+        self.framed(|bus| bus.write(&[Command::CmdWrite as u8, command]))
+    }
+
+    fn write_data(&mut self, data: u8) -> Result<(), Self::Error> {
+        self.framed(|bus| bus.write(&[Command::DataWrite as u8, data]))
+    }
+
+    fn read_data(&mut self) -> Result<u8, Self::Error> {
+        self.read_cycle(Command::DataRead)
+    }
+
+    fn read_status(&mut self) -> Result<u8, Self::Error> {
+        self.read_cycle(Command::CmdRead)
+    }
+
+    fn write_data_iter<I>(&mut self, data: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        self.framed(|bus| {
+            let mut buf = [0_u8; CHUNK_SIZE];
+            buf[0] = Command::DataWrite as u8;
+            let mut len = 1;
+            for byte in data {
+                buf[len] = byte;
+                len += 1;
+                if len == CHUNK_SIZE {
+                    bus.write(&buf)?;
+                    len = 0;
+                }
+            }
+            if len > 0 {
+                bus.write(&buf[..len])?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,58 @@
+//! Key-scan matrix configuration.
+//!
+//! The RA8875 can scan a matrix of up to 5 rows (KOUT) by 4 columns (KIN) on
+//! its own and raise the KEY interrupt when a key is pressed, freeing the host
+//! from dedicating GPIOs to a button matrix.
+
+/// How many consecutive scans must agree before a key press is reported.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum KeySampleTime {
+    #[default]
+    Scans4 = 0x00,
+    Scans8 = 0x10,
+    Scans16 = 0x20,
+    Scans32 = 0x30,
+}
+
+/// Key-scan settings, applied with `enable_keyscan`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct KeyScanConfig {
+    /// Debounce: the number of matching samples needed to accept a key.
+    pub sample_time: KeySampleTime,
+    /// Scan clock divider, 0..=7. Each step halves the scan rate.
+    pub scan_frequency: u8,
+    /// Report keys held past the long-press threshold with `KeyEvent::long_press` set.
+    pub long_press: bool,
+    /// Long-press threshold adjustment, 0..=3, from shortest to longest.
+    pub long_press_time: u8,
+    /// Let a key press wake the controller from sleep.
+    pub wake_on_key: bool,
+}
+
+/// A key reported by the key-scan controller.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KeyEvent {
+    /// The raw key code from the KSDR register.
+    pub code: u8,
+    /// Whether the key was held past the long-press threshold.
+    pub long_press: bool,
+}
+
+impl KeyEvent {
+    pub(crate) fn from_code(code: u8) -> Self {
+        KeyEvent {
+            code: code & 0x7F,
+            long_press: code & 0x80 != 0,
+        }
+    }
+
+    /// The KOUT line the key sits on.
+    pub fn row(&self) -> u8 {
+        self.code >> 4
+    }
+
+    /// The KIN line the key sits on.
+    pub fn column(&self) -> u8 {
+        self.code & 0x0F
+    }
+}
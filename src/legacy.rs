@@ -0,0 +1,33 @@
+//! The driver type as it was named before the move to `embedded-hal` 1.0.
+//!
+//! `RA8875` used to take the chip-select pin as its own type parameter, as in
+//! `RA8875<SPI, P, CS, O>`, and `RA8875::new` took it as an argument. The
+//! driver is now generic over an [`Interface`](::Interface) instead, which
+//! breaks both. Code written against the old API keeps its type annotations by
+//! importing `legacy::RA8875` in place of `ra8875::RA8875`, and builds the
+//! driver with `new_legacy`, which takes the same arguments the old `new` did:
+//!
+//! ```
+//! # extern crate embedded_hal;
+//! # extern crate ra8875;
+//! # use embedded_hal::digital::v2::{InputPin, OutputPin};
+//! # use embedded_hal::spi::FullDuplex;
+//! use ra8875::legacy::RA8875;
+//!
+//! fn build<SPI, P, CS, O>(spi: SPI, ready: P, cs: CS, rst: O) -> RA8875<SPI, P, CS, O>
+//! where
+//!     SPI: FullDuplex<u8>,
+//!     P: InputPin,
+//!     CS: OutputPin,
+//!     O: OutputPin,
+//! {
+//!     RA8875::new_legacy(spi, (800, 480), ready, cs, rst)
+//! }
+//! # fn main() {}
+//! ```
+
+use interface::LegacySpi;
+
+/// `RA8875` driven through a `FullDuplex` peripheral and a chip-select pin, with the type
+/// parameters in their old order.
+pub type RA8875<SPI, P, CS, O> = ::RA8875<LegacySpi<SPI, CS>, P, O>;
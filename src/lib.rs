@@ -6,25 +6,103 @@
 
 #[macro_use]
 extern crate nb;
+#[cfg(feature = "defmt")]
+extern crate defmt;
+#[cfg(feature = "embedded-graphics")]
 extern crate embedded_graphics;
 extern crate embedded_hal as hal;
+extern crate embedded_hal_1 as hal1;
+#[cfg(feature = "test-util")]
+extern crate std;
 
+use core::array;
 use core::fmt;
 use core::fmt::Write;
+use core::iter;
 
+#[cfg(feature = "embedded-graphics")]
 use embedded_graphics::{
-    pixelcolor::{IntoStorage, Rgb565},
+    pixelcolor::{raw::RawU16, IntoStorage, Rgb565, Rgb888},
     prelude::*,
     primitives,
 };
 
+use hal::blocking::delay::DelayMs;
 use hal::digital::v2::{InputPin, OutputPin};
+#[cfg(feature = "legacy-spi")]
 use hal::spi::FullDuplex;
 
-type SpiError<SPI> = <SPI as FullDuplex<u8>>::Error;
+pub mod builder;
+#[cfg(feature = "framebuffer")]
+pub mod framebuffer;
+pub mod interface;
+pub mod keyscan;
+#[cfg(feature = "legacy-spi")]
+pub mod legacy;
+pub mod palette;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+pub mod touch;
 
-#[derive(Copy, Clone)]
-enum Color {
+pub use builder::RA8875Builder;
+pub use cmds::{PllC1, PllC2};
+#[cfg(feature = "framebuffer")]
+pub use framebuffer::Framebuffer;
+pub use interface::{ExclusiveSpi, ExclusiveSpiError, Interface};
+#[cfg(feature = "legacy-spi")]
+pub use interface::{LegacyBlockingSpi, LegacySpi, LegacySpiError};
+pub use keyscan::{KeyEvent, KeySampleTime, KeyScanConfig};
+pub use palette::rgb;
+pub use touch::{TouchAdcClock, TouchConfig, TouchSampleTime};
+#[cfg(feature = "embedded-graphics")]
+pub use touch::{TouchCalibration, TouchSample, TouchStroke};
+
+type SpiError<SPI> = <SPI as Interface>::Error;
+type PinError<P> = <P as InputPin>::Error;
+
+/// Errors returned by the driver.
+#[derive(Debug)]
+pub enum Error<SpiE, PinE> {
+    /// The underlying SPI interface failed.
+    Spi(SpiE),
+    /// A GPIO pin failed.
+    Pin(PinE),
+    /// The requested circle radius doesn't fit the 8-bit radius register.
+    RadiusOutOfRange,
+    /// A caller-supplied buffer doesn't match the size of the region it describes.
+    InvalidBufferLength,
+    /// There is no built-in panel timing for the requested display dimensions.
+    UnsupportedDimensions,
+    /// A text enlargement factor is outside 0..=3.
+    TextScaleOutOfRange,
+    /// The chip didn't finish an operation within the configured poll limit.
+    Timeout,
+    /// The requested PLL settings put the VCO outside its operating range.
+    PllOutOfRange,
+    /// The chip didn't read back the PLL settings just written, so its system clock isn't
+    /// running as configured.
+    PllUnlocked,
+    /// A panel timing value is too small to encode in its register.
+    InvalidTiming,
+    /// A general purpose IO pin number is outside 0..=3.
+    GpioOutOfRange,
+    /// A cursor position lies outside the panel. Only reported with the `checked-coords`
+    /// feature enabled.
+    OutOfBounds,
+    /// The chip stayed busy for longer than the ready limit, e.g. because the `ready` pin is
+    /// miswired or its polarity is wrong.
+    NotReadyTimeout,
+}
+
+impl<SpiE, PinE> From<SpiE> for Error<SpiE, PinE> {
+    fn from(e: SpiE) -> Self {
+        Error::Spi(e)
+    }
+}
+
+/// Named RGB565 colors, usable anywhere the driver accepts a color.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Color {
     Black = 0x0000,
     Blue = 0x001F,
     Red = 0xF800,
@@ -35,8 +113,49 @@ enum Color {
     White = 0xFFFF,
 }
 
+impl From<Color> for u16 {
+    fn from(color: Color) -> u16 {
+        color as u16
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl From<Color> for Rgb565 {
+    fn from(color: Color) -> Rgb565 {
+        RawU16::new(color as u16).into()
+    }
+}
+
+/// A color accepted by the drawing methods: a raw `u16` in the current color depth's layout,
+/// a named `Color`, or an embedded-graphics `Rgb565`.
+///
+/// Implement it for your own color types to pass them in directly.
+pub trait DisplayColor {
+    /// The color as the raw value written to the color registers.
+    fn into_raw(self) -> u16;
+}
+
+impl DisplayColor for u16 {
+    fn into_raw(self) -> u16 {
+        self
+    }
+}
+
+impl DisplayColor for Color {
+    fn into_raw(self) -> u16 {
+        self as u16
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl DisplayColor for Rgb565 {
+    fn into_raw(self) -> u16 {
+        self.into_storage()
+    }
+}
+
 #[derive(Copy, Clone)]
-enum Command {
+pub(crate) enum Command {
     DataWrite = 0x00,
     DataRead = 0x40,
     CmdWrite = 0x80,
@@ -44,6 +163,7 @@ enum Command {
 }
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[allow(non_camel_case_types)]
 enum Register {
     SelfTest = 0x00,
@@ -52,6 +172,8 @@ enum Register {
     PllC1 = 0x88,
     PllC2 = 0x89,
     Sysr = 0x10,
+    Gpi = 0x12,
+    Gpo = 0x13,
     Pcsr = 0x04,
     Hdwr = 0x14,
     Hndftr = 0x15,
@@ -92,6 +214,9 @@ enum Register {
     Tpxyl = 0x74,
     Intc1 = 0xF0,
     Intc2 = 0xF1,
+    Kscr1 = 0xC0,
+    Kscr2 = 0xC1,
+    Ksdr0 = 0xC2,
     Becr0 = 0x50,
     Becr1 = 0x51,
     Hsbe0 = 0x54,
@@ -143,9 +268,42 @@ enum Register {
     EllipseCenterY0 = 0xa7,
     EllipseCenterY1 = 0xa8,
     GpioX = 0xC7,
+    Hofs0 = 0x24,
+    Hofs1 = 0x25,
+    Vofs0 = 0x26,
+    Vofs1 = 0x27,
+    Hssw0 = 0x38,
+    Hssw1 = 0x39,
+    Vssw0 = 0x3A,
+    Vssw1 = 0x3B,
+    Hesw0 = 0x3C,
+    Hesw1 = 0x3D,
+    Vesw0 = 0x3E,
+    Vesw1 = 0x3F,
+    Ltpr0 = 0x52,
+    Ltpr1 = 0x53,
+    Dpcr = 0x20,
+    Mwcr1 = 0x41,
+    Btcr = 0x44,
+    Curhs = 0x4E,
+    Curvs = 0x4F,
+    Ptno = 0x66,
+    Bgtr0 = 0x67,
+    Bgtr1 = 0x68,
+    Bgtr2 = 0x69,
+    Mrcd = 0x45,
+    RCurH0 = 0x4A,
+    RCurH1 = 0x4B,
+    RCurV0 = 0x4C,
+    RCurV1 = 0x4D,
+    Fncr0 = 0x21,
+    Sroc = 0x05,
+    Sfclr = 0x06,
+    Fwtsr = 0x2E,
+    Sfrs = 0x2F,
 }
 
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
 mod cmds {
     pub enum Pwrr {
         DispOn = 0x80,
@@ -154,10 +312,14 @@ mod cmds {
         Normal = 0x00,
         SoftReset = 0x01,
     }
+    /// PLL input divider.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub enum PllC1 {
         Div2 = 0x80,
         Div1 = 0x00,
     }
+    /// PLL output divider.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub enum PllC2 {
         Div1 = 0x00,
         Div2 = 0x01,
@@ -224,6 +386,41 @@ mod cmds {
     pub enum Mwcr0 {
         GfxMode = 0x00,
         TxtMode = 0x80,
+        CursorVisible = 0x40,
+        CursorBlink = 0x20,
+        DirMask = 0x0C,
+    }
+    pub enum Fncr0 {
+        CgramSelect = 0x80,
+        ExternalCgrom = 0x20,
+        SetMask = 0x03,
+    }
+    pub enum Fncr1 {
+        Transparent = 0x40,
+        Rotate90 = 0x10,
+        EnlargeMask = 0x0F,
+    }
+    pub enum Sroc {
+        // Serial ROM 0, 24-bit addressing, mode 3 waveform, font mode
+        FontRom = 0x28,
+    }
+    pub enum Sfclr {
+        SysClkDiv4 = 0x03,
+    }
+    pub enum Fwtsr {
+        Size16 = 0x00,
+        Size24 = 0x40,
+        Size32 = 0x80,
+        SizeMask = 0xC0,
+    }
+    pub enum Mrcd {
+        DirMask = 0x03,
+    }
+    pub enum Mwcr0Dir {
+        LeftRightTopDown = 0x00,
+        RightLeftTopDown = 0x04,
+        TopDownLeftRight = 0x08,
+        DownTopLeftRight = 0x0C,
     }
     pub enum P1cr {
         Enable = 0x80,
@@ -255,6 +452,21 @@ mod cmds {
         Div16384 = 0x0E,
         Div32768 = 0x0F,
     }
+    pub enum Stsr {
+        Busy = 0x80,
+        BteBusy = 0x40,
+        TouchEvent = 0x20,
+        Sleep = 0x02,
+        SerialRomBusy = 0x01,
+    }
+    pub enum Kscr1 {
+        Enable = 0x80,
+        LongKey = 0x40,
+    }
+    pub enum Kscr2 {
+        WakeUp = 0x80,
+        KeyCountMask = 0x03,
+    }
     pub enum Tpcr0 {
         ENABLE = 0x80,
         // DISABLE         =  0x00,
@@ -289,6 +501,9 @@ mod cmds {
         LATCHX = 0x02,
         LATCHY = 0x03,
     }
+    pub enum Tpxyl {
+        TouchDetected = 0x80,
+    }
     pub enum Intc1 {
         KEY = 0x10,
         DMA = 0x08,
@@ -301,6 +516,32 @@ mod cmds {
         TP = 0x04,
         BTE = 0x02,
     }
+    pub enum Ltpr0 {
+        ScrollModeMask = 0xC0,
+        DisplayModeMask = 0x07,
+    }
+    pub enum Dpcr {
+        TwoLayers = 0x80,
+        HDir = 0x08,
+        VDir = 0x04,
+    }
+    pub enum Mwcr1 {
+        Layer2 = 0x01,
+        DestPattern = 0x0C,
+    }
+    pub enum Ptno {
+        Size16x16 = 0x80,
+    }
+    pub enum Becr0 {
+        Enable = 0x80,
+    }
+    pub enum Becr1 {
+        MovePositive = 0x02,
+        MoveNegative = 0x03,
+        TransparentWrite = 0x04,
+        PatternFill = 0x06,
+        SolidFill = 0x0C,
+    }
 }
 
 type Coord = (i16, i16);
@@ -309,8 +550,11 @@ struct TextModeSettings {
     cursor: Coord,
     fg_color: u16,
     bg_color: Option<u16>,
-    text_scale: u8,
-    transparency: bool,
+    options: FontOptions,
+    font: FontSource,
+    /// Last value written to the FontOptions register.
+    fncr1: u8,
+    word_wrap: bool,
 }
 
 struct GraphicsModeSettings {
@@ -318,31 +562,505 @@ struct GraphicsModeSettings {
     color: u16,
 }
 
-#[derive(Copy, Clone)]
-enum Mode {
+/// Whether the controller is currently rendering text or graphics.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
     Text,
     Graphics,
 }
 
-pub struct RA8875<SPI: FullDuplex<u8>, P: InputPin, O1: OutputPin, O2: OutputPin> {
+/// Bits stored per pixel in display memory.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// RGB332, 256 colors. Halves memory use, leaving room for a second layer.
+    Bpp8,
+    /// RGB565, 65536 colors.
+    Bpp16,
+}
+
+/// The order the two bytes of an RGB565 pixel are sent in.
+///
+/// Most boards expect the high byte first. Boards whose bus swaps the bytes show red and blue
+/// exchanged (and green scrambled) until this is set to `LowFirst`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum ByteOrder {
+    #[default]
+    HighFirst,
+    LowFirst,
+}
+
+/// Genicomp serial font ROMs the RA8875 can read glyphs from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FontRom {
+    GT21L16T1W = 0x00,
+    GT30L16U2W = 0x20,
+    GT30L24T3Y = 0x40,
+    GT30L24M1Z = 0x60,
+    GT30L32S4W = 0x80,
+}
+
+/// Character encodings understood by the external font ROMs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FontEncoding {
+    GB2312 = 0x00,
+    GB12345 = 0x04,
+    Big5 = 0x08,
+    Unicode = 0x0C,
+    Ascii = 0x10,
+    UniJapanese = 0x14,
+    Jis0208 = 0x18,
+    Latin = 0x1C,
+}
+
+/// Character sets available in the internal font ROM.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum FontSet {
+    #[default]
+    Iso8859_1 = 0,
+    Iso8859_2 = 1,
+    Iso8859_3 = 2,
+    Iso8859_4 = 3,
+}
+
+/// Text rendering options, applied with `set_font_options`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct FontOptions {
+    /// Character set used by the internal ROM font.
+    pub set: FontSet,
+    /// Horizontal enlargement, 0..=3 for 1x to 4x.
+    pub enlarge_x: u8,
+    /// Vertical enlargement, 0..=3 for 1x to 4x.
+    pub enlarge_y: u8,
+    /// Rotate glyphs 90 degrees counterclockwise.
+    pub rotate_90: bool,
+    /// Draw glyphs without filling the background.
+    pub transparent: bool,
+}
+
+/// The driver's text drawing state, captured with `text_state` and reapplied with
+/// `restore_text_state`, e.g. around a nested widget draw.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TextState {
+    /// Text cursor position in pixels.
+    pub cursor: (i16, i16),
+    /// Foreground color, as the raw value written to the color registers.
+    pub fg_color: u16,
+    /// Background color as a raw value, or `None` for a transparent background.
+    pub bg_color: Option<u16>,
+    pub options: FontOptions,
+    pub font: FontSource,
+}
+
+/// Where text mode takes its glyphs from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FontSource {
+    /// The RA8875's built-in character ROM.
+    Internal,
+    /// An external serial font ROM using the given encoding.
+    External(FontRom, FontEncoding),
+}
+
+/// Clockwise rotation of the drawing coordinate frame relative to the panel.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rotation {
+    Rotate0,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+}
+
+/// How the Block Transfer Engine combines each source pixel (S) with the destination pixel
+/// (D) it lands on. Maps to the upper nibble of BECR1.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RasterOp {
+    /// Every bit cleared.
+    Black = 0x00,
+    /// `!(S | D)`
+    Nor = 0x10,
+    /// `!S & D`
+    NotSourceAndDestination = 0x20,
+    /// `!S`
+    NotSource = 0x30,
+    /// `S & !D`
+    SourceAndNotDestination = 0x40,
+    /// `!D`
+    NotDestination = 0x50,
+    /// `S ^ D`
+    Xor = 0x60,
+    /// `!(S & D)`
+    Nand = 0x70,
+    /// `S & D`
+    And = 0x80,
+    /// `!(S ^ D)`
+    Xnor = 0x90,
+    /// `D`, leaving the destination unchanged.
+    Destination = 0xA0,
+    /// `!S | D`
+    NotSourceOrDestination = 0xB0,
+    /// `S`, a plain copy.
+    Source = 0xC0,
+    /// `S | !D`
+    SourceOrNotDestination = 0xD0,
+    /// `S | D`
+    Or = 0xE0,
+    /// Every bit set.
+    White = 0xF0,
+}
+
+/// The axis a gradient runs along, as used by `fill_gradient`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GradientDirection {
+    /// From the left edge to the right edge.
+    Horizontal,
+    /// From the top edge to the bottom edge.
+    Vertical,
+}
+
+/// The order memory writes advance the cursor in, set with `set_write_direction`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WriteDirection {
+    /// Left to right, then top to bottom. The reset default.
+    LeftRightTopDown,
+    /// Right to left, then top to bottom.
+    RightLeftTopDown,
+    /// Top to bottom, then left to right.
+    TopDownLeftRight,
+    /// Bottom to top, then left to right.
+    DownTopLeftRight,
+}
+
+/// How the two display layers are composited when dual-layer mode is enabled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LayerMode {
+    /// Only layer 1 is visible.
+    Layer1Only = 0x00,
+    /// Only layer 2 is visible.
+    Layer2Only = 0x01,
+    /// The brighter of the two layers is shown at each pixel.
+    Lighten = 0x02,
+    /// Layer 1 is shown through layer 2 wherever layer 2 holds the transparent color.
+    Transparent = 0x03,
+    /// The two layers are combined with a bitwise OR.
+    Or = 0x04,
+    /// The two layers are combined with a bitwise AND.
+    And = 0x05,
+}
+
+/// A display layer that memory writes can be directed to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Layer {
+    Layer1,
+    Layer2,
+}
+
+/// Packs an RGB565 color down to the RGB332 format used in 8bpp mode.
+fn rgb565_to_rgb332(color: u16) -> u8 {
+    let r = (color >> 13) & 0x07;
+    let g = (color >> 8) & 0x07;
+    let b = (color >> 3) & 0x03;
+    ((r << 5) | (g << 2) | b) as u8
+}
+
+/// Expands an RGB332 color read back in 8bpp mode to RGB565.
+fn rgb332_to_rgb565(color: u8) -> u16 {
+    let r = ((color >> 5) & 0x07) as u16;
+    let g = ((color >> 2) & 0x07) as u16;
+    let b = (color & 0x03) as u16;
+    (r << 13) | (g << 8) | (b << 3)
+}
+
+/// The bytes sent over the bus for a single pixel at the given color depth.
+fn pixel_bytes(
+    depth: ColorDepth,
+    order: ByteOrder,
+    color: u16,
+) -> iter::Take<array::IntoIter<u8, 2>> {
+    match (depth, order) {
+        (ColorDepth::Bpp16, ByteOrder::HighFirst) => {
+            IntoIterator::into_iter([(color >> 8) as u8, color as u8]).take(2)
+        }
+        (ColorDepth::Bpp16, ByteOrder::LowFirst) => {
+            IntoIterator::into_iter([color as u8, (color >> 8) as u8]).take(2)
+        }
+        (ColorDepth::Bpp8, _) => IntoIterator::into_iter([rgb565_to_rgb332(color), 0]).take(1),
+    }
+}
+
+/// Scales the brightness of an RGB565 color by `factor / 255`, e.g. 128 for roughly half
+/// brightness when drawing a disabled widget.
+pub fn dim_rgb565(color: u16, factor: u8) -> u16 {
+    blend_rgb565(0x0000, color, factor)
+}
+
+/// Mixes two RGB565 colors channel by channel. An `alpha` of 0 gives `a`, 255 gives `b`, and
+/// values in between blend proportionally.
+pub fn blend_rgb565(a: u16, b: u16, alpha: u8) -> u16 {
+    let alpha = alpha as u32;
+    let mix = |shift: u16, mask: u16| {
+        let ca = ((a >> shift) & mask) as u32;
+        let cb = ((b >> shift) & mask) as u32;
+        (((ca * (255 - alpha) + cb * alpha + 127) / 255) as u16) << shift
+    };
+    mix(11, 0x1F) | mix(5, 0x3F) | mix(0, 0x1F)
+}
+
+/// `tan(d + 0.5°)` scaled by 1024 for `d` in 0..45, the rounding thresholds for `arc_angle`.
+const TAN_HALF_DEGREES: [i32; 45] = [
+    9, 27, 45, 63, 81, 99, 117, 135, 153, 171, 190, 208, 227, 246, 265, 284, 303, 323, 343, 363,
+    383, 403, 424, 445, 467, 488, 511, 533, 556, 579, 603, 628, 652, 678, 704, 730, 758, 786, 815,
+    844, 875, 906, 938, 972, 1006,
+];
+
+/// The angle in whole degrees, 0..360, of the offset `(dx, dy)` from a center point, measured
+/// clockwise from the positive x axis as seen on screen.
+fn arc_angle(dx: i32, dy: i32) -> u16 {
+    let (ax, ay) = (dx.abs(), dy.abs());
+    let octant = |a: i32, b: i32| {
+        let t = if a == 0 { 0 } else { b * 1024 / a };
+        TAN_HALF_DEGREES
+            .iter()
+            .filter(|&&threshold| threshold <= t)
+            .count() as u16
+    };
+    let base = if ay <= ax {
+        octant(ax, ay)
+    } else {
+        90 - octant(ay, ax)
+    };
+    match (dx >= 0, dy >= 0) {
+        (true, true) => base,
+        (false, true) => 180 - base,
+        (false, false) => 180 + base,
+        (true, false) => (360 - base) % 360,
+    }
+}
+
+/// Integer square root, rounded down.
+fn isqrt(n: i32) -> i32 {
+    let mut root = 0;
+    while (root + 1) * (root + 1) <= n {
+        root += 1;
+    }
+    root
+}
+
+/// Splits an RGB565 color into the per-channel values the color registers expect at the given
+/// color depth.
+fn color_components(depth: ColorDepth, color: u16) -> (u8, u8, u8) {
+    match depth {
+        ColorDepth::Bpp16 => (
+            ((color & 0xf800) >> 11) as u8,
+            ((color & 0x07e0) >> 5) as u8,
+            (color & 0x001f) as u8,
+        ),
+        ColorDepth::Bpp8 => (
+            ((color >> 13) & 0x07) as u8,
+            ((color >> 8) & 0x07) as u8,
+            ((color >> 3) & 0x03) as u8,
+        ),
+    }
+}
+
+/// Dimensions of a BTE fill pattern.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PatternSize {
+    Size8x8,
+    Size16x16,
+}
+
+/// Selects which layers move when the scroll window is scrolled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ScrollMode {
+    /// Layer 1 and layer 2 scroll together.
+    Simultaneous = 0x00,
+    /// Only layer 1 scrolls.
+    Layer1 = 0x40,
+    /// Only layer 2 scrolls.
+    Layer2 = 0x80,
+    /// Layer 2 is used as a scroll buffer for layer 1.
+    Buffer = 0xC0,
+}
+
+/// The value the RA8875 returns from register 0x00.
+pub const CHIP_ID: u8 = 0x75;
+
+/// Decoded contents of the status register, as returned by `status`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Status {
+    /// A memory read or write is still in progress.
+    pub memory_busy: bool,
+    /// The Block Transfer Engine is running.
+    pub bte_busy: bool,
+    /// The touch panel has detected a touch.
+    pub touch_event: bool,
+    /// The chip is in sleep mode.
+    pub sleeping: bool,
+    /// The serial flash or font ROM interface is busy.
+    pub serial_rom_busy: bool,
+}
+
+/// The implemented bits of PLLC1 (input divider and multiplier) and PLLC2 (output divider),
+/// compared by `pll_locked`.
+const PLLC1_MASK: u8 = 0x9F;
+const PLLC2_MASK: u8 = 0x07;
+
+/// How long bring-up waits for the PLL before giving up with `Error::PllUnlocked`. It
+/// normally locks within the first millisecond.
+pub(crate) const PLL_LOCK_TIMEOUT_MS: u8 = 10;
+
+/// Configuration registers captured by `dump_config`, in the order `restore_config` writes
+/// them back. The PLL comes first so the rest is clocked in at full speed, and PWRR last so
+/// the display only turns on once everything else is in place.
+const CONFIG_REGISTERS: [Register; 39] = [
+    Register::PllC1,
+    Register::PllC2,
+    Register::Sysr,
+    Register::Pcsr,
+    Register::Hdwr,
+    Register::Hndftr,
+    Register::Hndr,
+    Register::Hstr,
+    Register::Hpwr,
+    Register::Vdhr0,
+    Register::Vdhr1,
+    Register::Vndr0,
+    Register::Vndr1,
+    Register::Vstr0,
+    Register::Vstr1,
+    Register::Vpwr,
+    Register::Dpcr,
+    Register::Mwcr0,
+    Register::Mwcr1,
+    Register::Mrcd,
+    Register::Fncr0,
+    Register::FontOptions,
+    Register::Hsaw0,
+    Register::Hsaw1,
+    Register::Vsaw0,
+    Register::Vsaw1,
+    Register::Heaw0,
+    Register::Heaw1,
+    Register::Veaw0,
+    Register::Veaw1,
+    Register::Color0,
+    Register::Color1,
+    Register::Color2,
+    Register::TextBg0,
+    Register::TextBg1,
+    Register::TextBg2,
+    Register::P1cr,
+    Register::P1dcr,
+    Register::Pwrr,
+];
+
+/// A snapshot of the chip's configuration registers, taken with `dump_config` and written
+/// back with `restore_config`.
+///
+/// It covers the PLL, color depth, panel timing, scan direction, memory write direction,
+/// font options, active window, colors, and backlight PWM setup and display power. Display
+/// memory is not included.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DisplayConfig {
+    values: [u8; CONFIG_REGISTERS.len()],
+}
+
+impl DisplayConfig {
+    fn value(&self, register: Register) -> u8 {
+        CONFIG_REGISTERS
+            .iter()
+            .position(|&r| r as u8 == register as u8)
+            .map_or(0, |i| self.values[i])
+    }
+}
+
+/// The level the `ready` pin is driven to while the chip is busy.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReadyPolarity {
+    /// Low while busy, like the WAIT output on Adafruit's breakout.
+    ActiveLow,
+    /// High while busy.
+    ActiveHigh,
+}
+
+/// How the driver finds out whether the chip can accept another transaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReadySource {
+    /// Watch the `ready` pin.
+    Pin(ReadyPolarity),
+    /// Ignore the `ready` pin and poll the busy bit of the status register, for boards
+    /// that don't wire the pin up. Any `InputPin` can be passed as `ready` in this case.
+    Status,
+}
+
+/// Interrupt sources reported in INTC2.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Interrupt {
+    Key = 0x10,
+    Dma = 0x08,
+    Touch = 0x04,
+    Bte = 0x02,
+}
+
+/// Pending interrupt flags, as returned by `interrupt_status`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct InterruptFlags {
+    pub touch: bool,
+    pub key: bool,
+    pub dma: bool,
+    pub bte: bool,
+}
+
+pub struct RA8875<SPI: Interface, P: InputPin, O: OutputPin> {
     pub spi: SPI,
     dims: (u32, u32),
     text_settings: TextModeSettings,
     gfx_settings: GraphicsModeSettings,
     mode: Mode,
+    color_depth: ColorDepth,
+    byte_order: ByteOrder,
+    rotation: Rotation,
+    #[cfg(feature = "embedded-graphics")]
+    touch_calibration: Option<TouchCalibration>,
+    /// TPCR1 settings for manual touch mode, without the mode bits.
+    touch_manual: u8,
+    /// Active window corners in hardware coordinates.
+    window: (Coord, Coord),
+    /// Text window corners in hardware coordinates, used instead of `window` in text mode.
+    text_window: Option<(Coord, Coord)>,
+    pll: (u8, u8),
+    poll_limit: Option<u32>,
+    ready_limit: Option<u32>,
+    /// Consecutive busy checks since the chip was last ready.
+    busy_polls: u32,
+    ready_source: ReadySource,
     pub ready: P,
-    pub cs: O1,
-    pub rst: O2,
+    pub rst: O,
 }
 
-impl<SPI, P, O1, O2> RA8875<SPI, P, O1, O2>
+#[cfg(feature = "legacy-spi")]
+impl<SPI, CS, P, O> RA8875<LegacySpi<SPI, CS>, P, O>
 where
     SPI: FullDuplex<u8>,
+    CS: OutputPin,
+    P: InputPin,
+    O: OutputPin,
+{
+    /// Creates a driver from an `embedded-hal` 0.2 `FullDuplex` SPI peripheral and a
+    /// chip-select pin that the driver toggles itself.
+    pub fn new_legacy(spi: SPI, dims: (u32, u32), ready: P, cs: CS, rst: O) -> Self {
+        RA8875::new(LegacySpi::new(spi, cs), dims, ready, rst)
+    }
+}
+
+impl<SPI, P, O> RA8875<SPI, P, O>
+where
+    SPI: Interface,
     P: InputPin,
-    O1: OutputPin,
-    O2: OutputPin,
+    O: OutputPin,
 {
-    pub fn new(spi: SPI, dims: (u32, u32), ready: P, cs: O1, rst: O2) -> Self {
+    /// Creates a driver from an SPI interface, typically an `embedded-hal` 1.0 `SpiDevice`
+    /// which owns chip-select and may share its bus with other devices.
+    pub fn new(spi: SPI, dims: (u32, u32), ready: P, rst: O) -> Self {
         RA8875 {
             spi,
             dims,
@@ -350,587 +1068,2999 @@ where
                 cursor: (0, 0),
                 fg_color: 0,
                 bg_color: None,
-                text_scale: 1,
-                transparency: false,
+                options: FontOptions::default(),
+                font: FontSource::Internal,
+                fncr1: 0,
+                word_wrap: false,
             },
             gfx_settings: GraphicsModeSettings {
                 cursor: (0, 0),
                 color: 0,
             },
             mode: Mode::Graphics,
+            color_depth: ColorDepth::Bpp16,
+            byte_order: ByteOrder::HighFirst,
+            rotation: Rotation::Rotate0,
+            #[cfg(feature = "embedded-graphics")]
+            touch_calibration: None,
+            touch_manual: cmds::Tprc1::MANUAL as u8,
+            window: ((0, 0), (dims.0 as i16 - 1, dims.1 as i16 - 1)),
+            text_window: None,
+            pll: (cmds::PllC1::Div1 as u8 + 10, cmds::PllC2::Div4 as u8),
+            poll_limit: None,
+            ready_limit: None,
+            busy_polls: 0,
+            ready_source: ReadySource::Pin(ReadyPolarity::ActiveLow),
             ready,
-            cs,
             rst,
         }
     }
 
-    fn spi_send(&mut self, data: u8) -> Result<(), SpiError<SPI>> {
-        block!(self.spi.send(data))?;
-        block!(self.spi.read())?; // Dummy read, toss the result.
-        Ok(())
+    /// Selects how the driver detects that the chip is busy. Defaults to an active-low
+    /// `ready` pin.
+    pub fn set_ready_source(&mut self, source: ReadySource) {
+        self.ready_source = source;
     }
 
-    fn spi_read(&mut self) -> Result<u8, SpiError<SPI>> {
-        let dummy = 0_u8;
-        block!(self.spi.send(dummy))?; // Dummy write for full duplex
-        let result = block!(self.spi.read())?;
-        Ok(result)
+    /// Whether the chip is ready to accept another bus transaction. Every driver call waits
+    /// on this internally; it's exposed so callers can avoid starting a call while the chip
+    /// is busy.
+    pub fn is_ready(&mut self) -> Result<bool, Error<SpiError<SPI>, PinError<P>>> {
+        Ok(!self.busy()?)
     }
 
-    fn write_data(&mut self, data: u8) -> nb::Result<(), SpiError<SPI>> {
-        if self.ready.is_low().ok().unwrap() {
-            Err(nb::Error::WouldBlock)
-        } else {
-            self.cs.set_low().ok().unwrap();
-            self.spi_send(Command::DataWrite as u8).ok().unwrap();
-            self.spi_send(data).ok().unwrap();
-            self.cs.set_high().ok().unwrap();
-            Ok(())
+    fn busy(&mut self) -> Result<bool, Error<SpiError<SPI>, PinError<P>>> {
+        match self.ready_source {
+            ReadySource::Pin(ReadyPolarity::ActiveLow) => self.ready.is_low().map_err(Error::Pin),
+            ReadySource::Pin(ReadyPolarity::ActiveHigh) => self.ready.is_high().map_err(Error::Pin),
+            ReadySource::Status => {
+                let status = self.spi.read_status().map_err(Error::Spi)?;
+                Ok(status & cmds::Stsr::Busy as u8 != 0x00)
+            }
         }
     }
 
-    fn read_data(&mut self) -> nb::Result<u8, SpiError<SPI>> {
-        if self.ready.is_low().ok().unwrap() {
-            Err(nb::Error::WouldBlock)
-        } else {
-            self.cs.set_low().ok().unwrap();
-            self.spi_send(Command::DataRead as u8).ok().unwrap();
-            let result = self.spi_read().ok().unwrap();
-            self.cs.set_high().ok().unwrap();
-            Ok(result)
+    /// Returns `WouldBlock` while the chip is busy, or `Error::NotReadyTimeout` once it has
+    /// been busy for as many consecutive checks as the ready limit allows.
+    fn check_ready(&mut self) -> nb::Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if !self.busy()? {
+            self.busy_polls = 0;
+            return Ok(());
+        }
+        self.busy_polls = self.busy_polls.saturating_add(1);
+        if let Some(limit) = self.ready_limit {
+            if self.busy_polls >= limit {
+                self.busy_polls = 0;
+                return Err(nb::Error::Other(Error::NotReadyTimeout));
+            }
         }
+        Err(nb::Error::WouldBlock)
     }
 
-    fn write_command(&mut self, command: u8) -> nb::Result<(), SpiError<SPI>> {
-        if self.ready.is_low().ok().unwrap() {
-            Err(nb::Error::WouldBlock)
-        } else {
-            self.cs.set_low().ok().unwrap();
-            self.spi_send(Command::CmdWrite as u8).ok().unwrap();
-            self.spi_send(command).ok().unwrap();
-            self.cs.set_high().ok().unwrap();
-            Ok(())
-        }
+    fn write_data(&mut self, data: u8) -> nb::Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.check_ready()?;
+        self.spi.write_data(data).map_err(Error::Spi)?;
+        Ok(())
     }
 
-    fn read_status(&mut self) -> nb::Result<u8, SpiError<SPI>> {
-        if self.ready.is_low().ok().unwrap() {
-            Err(nb::Error::WouldBlock)
-        } else {
-            self.cs.set_low().ok().unwrap();
-            self.spi_send(Command::CmdRead as u8).ok().unwrap();
-            let result = self.spi_read().ok().unwrap();
-            self.cs.set_high().ok().unwrap();
-            Ok(result)
+    fn read_data(&mut self) -> nb::Result<u8, Error<SpiError<SPI>, PinError<P>>> {
+        self.check_ready()?;
+        Ok(self.spi.read_data().map_err(Error::Spi)?)
+    }
+
+    fn write_command(&mut self, command: u8) -> nb::Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.check_ready()?;
+        self.spi.write_command(command).map_err(Error::Spi)?;
+        Ok(())
+    }
+
+    fn read_status(&mut self) -> nb::Result<u8, Error<SpiError<SPI>, PinError<P>>> {
+        // Polling the status register doesn't need to wait for the chip.
+        if self.ready_source != ReadySource::Status {
+            self.check_ready()?;
         }
+        Ok(self.spi.read_status().map_err(Error::Spi)?)
     }
 
-    fn write_register(&mut self, register: Register, data: u8) -> Result<(), SpiError<SPI>> {
+    fn write_register(
+        &mut self,
+        register: Register,
+        data: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        #[cfg(feature = "defmt")]
+        defmt::trace!("write {} = {=u8:#04x}", register, data);
         block!(self.write_command(register as u8))?;
         block!(self.write_data(data))?;
         Ok(())
     }
 
-    fn read_register(&mut self, register: Register) -> Result<u8, SpiError<SPI>> {
+    fn read_register(
+        &mut self,
+        register: Register,
+    ) -> Result<u8, Error<SpiError<SPI>, PinError<P>>> {
         block!(self.write_command(register as u8))?;
-        block!(self.read_data())
+        let data = block!(self.read_data())?;
+        #[cfg(feature = "defmt")]
+        defmt::trace!("read {} = {=u8:#04x}", register, data);
+        Ok(data)
     }
 
-    pub fn self_check(&mut self) -> Result<u8, SpiError<SPI>> {
-        self.read_register(Register::SelfTest)
+    /// The panel dimensions the driver was constructed with, unaffected by rotation.
+    pub fn dimensions(&self) -> (u32, u32) {
+        self.dims
     }
 
-    pub fn set_up_pll(&mut self) -> Result<(), SpiError<SPI>> {
-        self.write_register(Register::PllC1, cmds::PllC1::Div1 as u8 + 10)?;
-        self.write_register(Register::PllC2, cmds::PllC2::Div4 as u8)
+    /// The mode the controller is currently in.
+    pub fn current_mode(&self) -> Mode {
+        self.mode
     }
 
-    pub fn init(&mut self) -> Result<(), SpiError<SPI>> {
-        let (width, height) = self.dims;
-        self.write_register(Register::Sysr, cmds::Sysr::BBP_16 as u8)?;
-        let t = match self.dims {
-            (480, 272) => Timing {
-                pixclk: cmds::Pcsr::Pdatl as u8 | cmds::Pcsr::Clk_4 as u8,
-                hsync_nondisp: 10,
-                hsync_start: 8,
-                hsync_pw: 48,
-                hsync_finetune: 0,
-                vsync_nondisp: 3,
-                vsync_start: 8,
-                vsync_pw: 10,
-            },
-            (800, 480) => Timing {
-                pixclk: cmds::Pcsr::Pdatl as u8 | cmds::Pcsr::Clk_2 as u8,
-                hsync_nondisp: 26,
-                hsync_start: 32,
-                hsync_pw: 96,
-                hsync_finetune: 0,
-                vsync_nondisp: 32,
-                vsync_start: 23,
-                vsync_pw: 2,
-            },
-            _ => {
-                panic!("Unsupported display dimensions.");
-            }
-        };
-        self.write_register(Register::Pcsr, t.pixclk)?;
-
-        self.write_register(Register::Hdwr, ((width / 8) - 1) as u8)?;
-        self.write_register(
-            Register::Hndftr,
-            cmds::Hndftr::High as u8 + t.hsync_finetune,
-        )?;
-        self.write_register(Register::Hndr, (t.hsync_nondisp - t.hsync_finetune - 2) / 8)?;
-        self.write_register(Register::Hstr, t.hsync_start / 8 - 1)?;
-        self.write_register(Register::Hpwr, cmds::Hpwr::Low as u8 + t.hsync_pw / 8 - 1)?;
-
-        self.write_register(Register::Vdhr0, ((height - 1) & 0xFF) as u8)?;
-        self.write_register(Register::Vdhr1, ((height - 1) >> 8) as u8)?;
-        self.write_register(Register::Vndr0, (t.vsync_nondisp - 1) as u8)?;
-        self.write_register(Register::Vndr1, (t.vsync_nondisp >> 8) as u8)?;
-        self.write_register(Register::Vstr0, (t.vsync_start - 1) as u8)?;
-        self.write_register(Register::Vstr1, (t.vsync_start >> 8) as u8)?;
-        self.write_register(Register::Vpwr, cmds::Vpwr::Low as u8 + t.vsync_pw - 1)?;
-
-        self.write_register(Register::Hsaw0, 0)?;
-        self.write_register(Register::Hsaw1, 0)?;
-        self.write_register(Register::Heaw0, ((width - 1) & 0xFF) as u8)?;
-        self.write_register(Register::Heaw1, ((width - 1) >> 8) as u8)?;
-
-        self.write_register(Register::Vsaw0, 0)?;
-        self.write_register(Register::Vsaw1, 0)?;
-        self.write_register(Register::Veaw0, ((height - 1) & 0xFF) as u8)?;
-        self.write_register(Register::Veaw1, ((height - 1) >> 8) as u8)?;
-
-        // Clear screen
-        self.write_register(Register::Mclr, cmds::Mclr::Start as u8)?;
+    /// The last cursor position set for the current mode.
+    pub fn cursor(&self) -> (i16, i16) {
+        match self.mode {
+            Mode::Text => self.text_settings.cursor,
+            Mode::Graphics => self.gfx_settings.cursor,
+        }
+    }
 
-        Ok(())
+    /// Caps how many times the driver polls for a drawing or BTE operation to finish before
+    /// giving up with `Error::Timeout`. `None`, the default, waits indefinitely.
+    pub fn set_poll_limit(&mut self, limit: Option<u32>) {
+        self.poll_limit = limit;
     }
 
-    pub fn display_on(&mut self, on: bool) -> Result<(), SpiError<SPI>> {
-        if on {
-            self.write_register(
-                Register::Pwrr,
-                cmds::Pwrr::Normal as u8 | cmds::Pwrr::DispOn as u8,
-            )
-        } else {
-            self.write_register(Register::Pwrr, cmds::Pwrr::Normal as u8)
-        }
+    /// Caps how many consecutive times the driver checks for the chip to become ready before
+    /// giving up with `Error::NotReadyTimeout`. `None`, the default, waits indefinitely.
+    ///
+    /// Each check is a single read of the `ready` pin (or the status register), so the limit
+    /// should allow for many checks per microsecond of expected busy time. Setting one during
+    /// bring-up turns a miswired or inverted `ready` pin into an error instead of a hang.
+    pub fn set_ready_limit(&mut self, limit: Option<u32>) {
+        self.ready_limit = limit;
     }
 
-    pub fn gpiox(&mut self, on: bool) -> Result<(), SpiError<SPI>> {
-        if on {
-            self.write_register(Register::GpioX, 1)
-        } else {
-            self.write_register(Register::GpioX, 0)
+    /// Polls `register` until `bit` clears, honouring the poll limit.
+    fn wait_for_draw_complete(
+        &mut self,
+        register: Register,
+        bit: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let mut polls = 0_u32;
+        while (self.read_register(register)? & bit) != 0x00 {
+            polls += 1;
+            if let Some(limit) = self.poll_limit {
+                if polls >= limit {
+                    return Err(Error::Timeout);
+                }
+            }
         }
+        Ok(())
     }
 
-    pub fn pwm1_out(&mut self, pulse: u8) -> Result<(), SpiError<SPI>> {
-        self.write_register(Register::P1dcr, pulse)
+    /// Writes `value` to the register at `address`, for registers the driver doesn't wrap.
+    ///
+    /// This is an escape hatch for advanced use. The driver caches some chip state (mode,
+    /// color depth, font options, the active window, ...) and won't notice registers changed
+    /// behind its back, so poking anything it manages can leave the two out of sync.
+    pub fn write_raw_register(
+        &mut self,
+        address: u8,
+        value: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        block!(self.write_command(address))?;
+        block!(self.write_data(value))?;
+        Ok(())
     }
 
-    pub fn pwm1_config(&mut self, on: bool, clock: u8) -> Result<(), SpiError<SPI>> {
-        if on {
-            self.write_register(Register::P1cr, cmds::P1cr::Enable as u8 | (clock & 0xF))
-        } else {
-            self.write_register(Register::P1cr, clock & 0xF)
-        }
+    /// Reads the register at `address`. See `write_raw_register` for the caveats.
+    pub fn read_raw_register(
+        &mut self,
+        address: u8,
+    ) -> Result<u8, Error<SpiError<SPI>, PinError<P>>> {
+        block!(self.write_command(address))?;
+        Ok(block!(self.read_data())?)
     }
 
-    pub fn pwm2_out(&mut self, pulse: u8) -> Result<(), SpiError<SPI>> {
-        self.write_register(Register::P2dcr, pulse)
+    /// Swaps in a different SPI interface and returns the old one, e.g. to move from a slow
+    /// bring-up clock to full speed once the PLL has locked.
+    ///
+    /// Until the PLL runs, the chip is clocked straight from the crystal and can only keep up
+    /// with a correspondingly slow SPI clock. Run `reset` and `set_up_pll_checked` on a slow
+    /// interface, swap in the fast one, and then call `init`.
+    pub fn replace_spi(&mut self, spi: SPI) -> SPI {
+        core::mem::replace(&mut self.spi, spi)
     }
-    pub fn pwm2_config(&mut self, on: bool, clock: u8) -> Result<(), SpiError<SPI>> {
-        if on {
-            self.write_register(Register::P2cr, cmds::P2cr::Enable as u8 | (clock & 0xF))
-        } else {
-            self.write_register(Register::P2cr, clock & 0xF)
-        }
+
+    /// Performs a hardware reset by pulsing the `rst` pin low, then waits for the chip to come
+    /// back up before returning.
+    pub fn reset<D: DelayMs<u8>>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>>
+    where
+        O: OutputPin<Error = PinError<P>>,
+    {
+        self.rst.set_low().map_err(Error::Pin)?;
+        delay.delay_ms(1);
+        self.rst.set_high().map_err(Error::Pin)?;
+        delay.delay_ms(100);
+        Ok(())
     }
 
-    /// Enables text mode
+    /// Runs the full power-on sequence: hardware reset, PLL setup, then `init`.
     ///
-    /// This currently forces the user to select the internal ROM font.
-    pub fn text_mode(&mut self) -> Result<(), SpiError<SPI>> {
-        match self.mode {
-            Mode::Text => Ok(()),
-            Mode::Graphics => {
-                let tmp = self.read_register(Register::Mwcr0)?;
-                block!(self.write_data(tmp | cmds::Mwcr0::TxtMode as u8))?;
+    /// Returns `Error::PllUnlocked` if the PLL doesn't come up, see `wait_for_pll`, rather than
+    /// configuring a chip that can't run.
+    pub fn init_with_reset<D: DelayMs<u8>>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>>
+    where
+        O: OutputPin<Error = PinError<P>>,
+    {
+        self.reset(delay)?;
+        self.set_up_pll()?;
+        // Let the PLL lock before clocking the rest of the configuration in.
+        self.wait_for_pll(delay, PLL_LOCK_TIMEOUT_MS)?;
+        self.init()
+    }
 
-                // Sets the internal ROM font.
-                // TODO: Get the register names + values for this so it isn't so cryptic.
-                block!(self.write_command(0x21))?;
-                let tmp = block!(self.read_data())?;
-                block!(self.write_data(tmp & ((1 << 7) | (1 << 5))))?;
+    /// Reads register 0x00, which holds `CHIP_ID` on a working RA8875.
+    pub fn self_check(&mut self) -> Result<u8, Error<SpiError<SPI>, PinError<P>>> {
+        self.read_register(Register::SelfTest)
+    }
 
-                // Clear serial font ROM settings
-                block!(self.write_command(0x2F))?;
-                block!(self.write_data(0x00))?;
+    /// Whether an RA8875 answers on the bus. A `false` here usually points at wiring, the
+    /// SPI mode, or a chip that hasn't come out of reset.
+    pub fn is_present(&mut self) -> Result<bool, Error<SpiError<SPI>, PinError<P>>> {
+        Ok(self.self_check()? == CHIP_ID)
+    }
 
-                self.mode = Mode::Text;
+    /// Reads back the configuration registers, e.g. to stash in a static before powering
+    /// the chip down.
+    pub fn dump_config(&mut self) -> Result<DisplayConfig, Error<SpiError<SPI>, PinError<P>>> {
+        let mut config = DisplayConfig {
+            values: [0; CONFIG_REGISTERS.len()],
+        };
+        for (value, &register) in config.values.iter_mut().zip(CONFIG_REGISTERS.iter()) {
+            *value = self.read_register(register)?;
+        }
+        Ok(config)
+    }
 
-                Ok(())
+    /// Writes back a configuration taken with `dump_config`, as a faster alternative to `init`
+    /// after the chip lost power. Display memory isn't cleared, so redraw the screen after.
+    ///
+    /// The PLL is programmed first, and the remaining registers are only written once it has
+    /// locked. Returns `Error::PllUnlocked` if it doesn't.
+    pub fn restore_config<D: DelayMs<u8>>(
+        &mut self,
+        config: &DisplayConfig,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        // `wait_for_pll` checks the chip against the cached settings, so update them first.
+        self.pll = (config.value(Register::PllC1), config.value(Register::PllC2));
+        for (&value, &register) in config.values.iter().zip(CONFIG_REGISTERS.iter()) {
+            self.write_register(register, value)?;
+            if register as u8 == Register::PllC2 as u8 {
+                self.wait_for_pll(delay, PLL_LOCK_TIMEOUT_MS)?;
             }
         }
-    }
 
-    pub fn set_text_scale(&mut self, scale: u8) -> Result<(), SpiError<SPI>> {
-        let bit_pattern = match scale {
-            0 => 0b0000,
-            1 => 0b0101,
-            2 => 0b1010,
-            3 => 0b1111,
-            _ => 0b1111,
+        // Bring the driver's cached view of the chip back in line with the restored registers.
+        self.color_depth = if config.value(Register::Sysr) & cmds::Sysr::BBP_16 as u8 != 0 {
+            ColorDepth::Bpp16
+        } else {
+            ColorDepth::Bpp8
         };
-        let mut tmp = self.read_register(Register::FontOptions)?;
-        tmp &= !(0xF);
-        block!(self.write_data(tmp | bit_pattern))?;
-
-        self.text_settings.text_scale = scale;
-
-        Ok(())
-    }
+        self.mode = if config.value(Register::Mwcr0) & cmds::Mwcr0::TxtMode as u8 != 0 {
+            Mode::Text
+        } else {
+            Mode::Graphics
+        };
+        self.text_settings.fncr1 = config.value(Register::FontOptions);
+        let word = |low: Register, high: Register| {
+            (config.value(low) as i16) | ((config.value(high) as i16) << 8)
+        };
+        self.window = (
+            (
+                word(Register::Hsaw0, Register::Hsaw1),
+                word(Register::Vsaw0, Register::Vsaw1),
+            ),
+            (
+                word(Register::Heaw0, Register::Heaw1),
+                word(Register::Veaw0, Register::Veaw1),
+            ),
+        );
+        Ok(())
+    }
 
-    /// Enables graphics mode
-    pub fn graphics_mode(&mut self) -> Result<(), SpiError<SPI>> {
+    /// Reads and decodes the status register.
+    pub fn status(&mut self) -> Result<Status, Error<SpiError<SPI>, PinError<P>>> {
+        let status = block!(self.read_status())?;
+        Ok(Status {
+            memory_busy: status & cmds::Stsr::Busy as u8 != 0x00,
+            bte_busy: status & cmds::Stsr::BteBusy as u8 != 0x00,
+            touch_event: status & cmds::Stsr::TouchEvent as u8 != 0x00,
+            sleeping: status & cmds::Stsr::Sleep as u8 != 0x00,
+            serial_rom_busy: status & cmds::Stsr::SerialRomBusy as u8 != 0x00,
+        })
+    }
+
+    /// Programs the PLL for a 20 MHz crystal. Follow it with `wait_for_pll`, or use
+    /// `set_up_pll_checked`, before configuring anything else.
+    pub fn set_up_pll(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_pll(cmds::PllC1::Div1 as u8 + 10, cmds::PllC2::Div4 as u8)
+    }
+
+    /// Like `set_up_pll`, but also waits for the PLL to lock, returning `Error::PllUnlocked`
+    /// if it doesn't.
+    pub fn set_up_pll_checked<D: DelayMs<u8>>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.set_up_pll()?;
+        self.wait_for_pll(delay, PLL_LOCK_TIMEOUT_MS)
+    }
+
+    /// Configures the PLL for a different crystal or system clock than `set_up_pll` assumes.
+    ///
+    /// The system clock is `crystal_hz * (multiplier + 1) / input_div / output_div`. The VCO,
+    /// `crystal_hz * (multiplier + 1) / input_div`, must land within 100-300 MHz and
+    /// `multiplier` must fit the 5-bit field, otherwise `Error::PllOutOfRange` is returned
+    /// without touching the chip. Follow it with `wait_for_pll` before configuring anything
+    /// else.
+    pub fn set_pll(
+        &mut self,
+        crystal_hz: u32,
+        input_div: PllC1,
+        multiplier: u8,
+        output_div: PllC2,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let divider = match input_div {
+            PllC1::Div1 => 1,
+            PllC1::Div2 => 2,
+        };
+        let vco = crystal_hz as u64 * (multiplier as u64 + 1) / divider;
+        if multiplier > 0x1F || !(100_000_000..=300_000_000).contains(&vco) {
+            return Err(Error::PllOutOfRange);
+        }
+        self.write_pll(input_div as u8 | multiplier, output_div as u8)
+    }
+
+    /// Whether the PLL appears to be running with the settings last written.
+    ///
+    /// The RA8875 has no lock flag, so this reads PLLC1 and PLLC2 back and compares them to
+    /// what was written. Register reads are served by the system clock the PLL drives, so a
+    /// PLL that failed to start, like a chip that isn't answering at all, reads back wrong.
+    pub fn pll_locked(&mut self) -> Result<bool, Error<SpiError<SPI>, PinError<P>>> {
+        let (pllc1, pllc2) = self.pll;
+        let read1 = self.read_register(Register::PllC1)?;
+        let read2 = self.read_register(Register::PllC2)?;
+        Ok(read1 & PLLC1_MASK == pllc1 & PLLC1_MASK && read2 & PLLC2_MASK == pllc2 & PLLC2_MASK)
+    }
+
+    /// Waits up to `timeout_ms` milliseconds for `pll_locked` to report the PLL running,
+    /// returning `Error::PllUnlocked` if it never does.
+    ///
+    /// Call it after `set_up_pll` or `set_pll`, so a bad PLL setting fails bring-up with an
+    /// error instead of a blank screen. The bring-up helpers, `wake`, and `restore_config`
+    /// already do.
+    pub fn wait_for_pll<D: DelayMs<u8>>(
+        &mut self,
+        delay: &mut D,
+        timeout_ms: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        for _ in 0..timeout_ms {
+            delay.delay_ms(1);
+            if self.pll_locked()? {
+                return Ok(());
+            }
+        }
+        Err(Error::PllUnlocked)
+    }
+
+    /// Writes the PLL control registers and remembers them so `wake` can restore them.
+    fn write_pll(&mut self, pllc1: u8, pllc2: u8) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::PllC1, pllc1)?;
+        self.write_register(Register::PllC2, pllc2)?;
+        self.pll = (pllc1, pllc2);
+        Ok(())
+    }
+
+    /// Configures the panel timing and clears display memory, returning once the clear is done.
+    ///
+    /// Returns `Error::UnsupportedDimensions` without touching the chip if there's no built-in
+    /// timing for the display's dimensions; use `init_with_timing` for other panels.
+    pub fn init(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        match Timing::for_dimensions(self.dims) {
+            Some(t) => self.init_with_timing(t),
+            None => Err(Error::UnsupportedDimensions),
+        }
+    }
+
+    /// Programs the PLL, waits for it to lock, and then runs `init`.
+    ///
+    /// `init` alone assumes the PLL was already set up and settled, as `set_up_pll` followed by
+    /// a short delay would do. This performs both steps, and returns `Error::PllUnlocked` if
+    /// the PLL doesn't lock.
+    pub fn init_with_delay<D: DelayMs<u8>>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (pllc1, pllc2) = self.pll;
+        self.write_pll(pllc1, pllc2)?;
+        self.wait_for_pll(delay, PLL_LOCK_TIMEOUT_MS)?;
+        self.init()
+    }
+
+    /// Configures the panel with caller-supplied timing and clears display memory.
+    ///
+    /// Returns `Error::InvalidTiming` without touching the chip if a sync or non-display value
+    /// is too small for its register encoding.
+    pub fn init_with_timing(&mut self, t: Timing) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (width, height) = self.dims;
+        let regs = t.registers().ok_or(Error::InvalidTiming)?;
+        self.write_register(Register::Sysr, cmds::Sysr::BBP_16 as u8)?;
+        self.color_depth = ColorDepth::Bpp16;
+        self.write_register(Register::Pcsr, t.pixclk)?;
+
+        self.write_register(Register::Hdwr, ((width / 8) - 1) as u8)?;
+        self.write_register(
+            Register::Hndftr,
+            cmds::Hndftr::High as u8 + t.hsync_finetune,
+        )?;
+        self.write_register(Register::Hndr, regs.hndr)?;
+        self.write_register(Register::Hstr, regs.hstr)?;
+        self.write_register(Register::Hpwr, cmds::Hpwr::Low as u8 + regs.hpwr)?;
+
+        self.write_register(Register::Vdhr0, ((height - 1) & 0xFF) as u8)?;
+        self.write_register(Register::Vdhr1, ((height - 1) >> 8) as u8)?;
+        self.write_register(Register::Vndr0, regs.vndr as u8)?;
+        self.write_register(Register::Vndr1, (regs.vndr >> 8) as u8)?;
+        self.write_register(Register::Vstr0, regs.vstr as u8)?;
+        self.write_register(Register::Vstr1, (regs.vstr >> 8) as u8)?;
+        self.write_register(Register::Vpwr, cmds::Vpwr::Low as u8 + regs.vpwr)?;
+
+        self.reset_active_window()?;
+
+        self.text_settings.fncr1 = self.read_register(Register::FontOptions)?;
+
+        // Clear screen, and let the clear finish so nothing drawn afterwards gets wiped
+        self.write_register(Register::Mclr, cmds::Mclr::Start as u8)?;
+        self.wait_for_memory_clear()
+    }
+
+    /// Clears the active window to black and waits for the clear to finish.
+    ///
+    /// The chip's memory clear fills with the text background color, so that is set to black
+    /// for the clear and restored afterwards. This is quicker than filling a rectangle, since
+    /// the chip clears memory without going through the drawing engine.
+    pub fn clear_memory(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.clear_to_black(cmds::Mclr::Start as u8 | cmds::Mclr::Active as u8)
+    }
+
+    /// Runs a memory clear, with `mclr` selecting the full or the active window, while the
+    /// background color registers are set to black.
+    ///
+    /// The clear fills with the text background color, which may hold anything, so it's
+    /// zeroed for the clear and the cached text background written back afterwards.
+    fn clear_to_black(&mut self, mclr: u8) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::TextBg0, 0)?;
+        self.write_register(Register::TextBg1, 0)?;
+        self.write_register(Register::TextBg2, 0)?;
+        self.write_register(Register::Mclr, mclr)?;
+        self.wait_for_memory_clear()?;
+        if let Some(bg_color) = self.text_settings.bg_color {
+            let (r, g, b) = color_components(self.color_depth, bg_color);
+            self.write_register(Register::TextBg0, r)?;
+            self.write_register(Register::TextBg1, g)?;
+            self.write_register(Register::TextBg2, b)?;
+        }
+        Ok(())
+    }
+
+    /// Polls the MCLR start bit, which stays set while a memory clear is running.
+    fn wait_for_memory_clear(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.wait_for_draw_complete(Register::Mclr, cmds::Mclr::Start as u8)
+    }
+
+    /// Turns the panel output on or off. This is the lightest of the power states: the
+    /// controller keeps running and the backlight stays as it is, so an "off" panel still
+    /// glows. `standby` also switches off the backlight, and `sleep` stops the controller.
+    pub fn display_on(&mut self, on: bool) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if on {
+            self.write_register(
+                Register::Pwrr,
+                cmds::Pwrr::Normal as u8 | cmds::Pwrr::DispOn as u8,
+            )
+        } else {
+            self.write_register(Register::Pwrr, cmds::Pwrr::Normal as u8)
+        }
+    }
+
+    /// Turns the display off and puts the controller into sleep mode.
+    ///
+    /// Sleep stops the PLL and the system clock, so the controller's own draw drops from the
+    /// tens of milliamps it uses while refreshing the panel to standby leakage. The backlight is
+    /// powered separately; switch it off as well (e.g. with `pwm1_out(0)`) to see most of the
+    /// savings. Display memory is retained. Call `wake` before talking to the chip again.
+    pub fn sleep(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.display_on(false)?;
+        self.write_register(Register::Pwrr, cmds::Pwrr::Sleep as u8)
+    }
+
+    /// Leaves sleep mode, reprograms the PLL, waits for it to lock, and turns the display
+    /// back on. Returns `Error::PllUnlocked` if the PLL doesn't lock.
+    pub fn wake<D: DelayMs<u8>>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::Pwrr, cmds::Pwrr::Normal as u8)?;
+        let (pllc1, pllc2) = self.pll;
+        self.write_pll(pllc1, pllc2)?;
+        self.wait_for_pll(delay, PLL_LOCK_TIMEOUT_MS)?;
+        self.display_on(true)
+    }
+
+    /// Blanks the screen the way a user expects "screen off" to look: the panel output and
+    /// the PWM1 backlight are turned off, and with `panel_off` the panel itself is disabled
+    /// through GPIOX as well.
+    ///
+    /// The controller keeps running, so display memory is retained and can still be drawn
+    /// to while in standby. It sits between `display_on(false)`, which leaves the backlight
+    /// lit, and `sleep`, which also stops the controller's clocks. Leave with `resume`.
+    pub fn standby(&mut self, panel_off: bool) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.backlight_on(false)?;
+        self.display_on(false)?;
+        if panel_off {
+            self.gpiox(false)?;
+        }
+        Ok(())
+    }
+
+    /// Leaves `standby`, enabling the panel, its output, and then the backlight.
+    pub fn resume(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.gpiox(true)?;
+        self.display_on(true)?;
+        self.backlight_on(true)
+    }
+
+    /// Drives the GPIOX pin, which enables the panel on Adafruit boards.
+    pub fn gpiox(&mut self, on: bool) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if on {
+            self.write_register(Register::GpioX, 1)
+        } else {
+            self.write_register(Register::GpioX, 0)
+        }
+    }
+
+    /// Drives general purpose output `pin` (0..=3) high or low.
+    ///
+    /// The outputs share the KOUT pins with the key-scan matrix and are only usable while key
+    /// scanning is disabled.
+    pub fn set_gpo(
+        &mut self,
+        pin: u8,
+        high: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if pin > 3 {
+            return Err(Error::GpioOutOfRange);
+        }
+        let tmp = self.read_register(Register::Gpo)?;
+        if high {
+            block!(self.write_data(tmp | (1 << pin)))?;
+        } else {
+            block!(self.write_data(tmp & !(1 << pin)))?;
+        }
+        Ok(())
+    }
+
+    /// Reads the level of general purpose input `pin` (0..=3).
+    ///
+    /// The inputs share the KIN pins with the key-scan matrix and are only usable while key
+    /// scanning is disabled.
+    pub fn read_gpi(&mut self, pin: u8) -> Result<bool, Error<SpiError<SPI>, PinError<P>>> {
+        if pin > 3 {
+            return Err(Error::GpioOutOfRange);
+        }
+        Ok(self.read_register(Register::Gpi)? & (1 << pin) != 0)
+    }
+
+    pub fn pwm1_out(&mut self, pulse: u8) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::P1dcr, pulse)
+    }
+
+    pub fn pwm1_config(
+        &mut self,
+        on: bool,
+        clock: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if on {
+            self.write_register(Register::P1cr, cmds::P1cr::Enable as u8 | (clock & 0xF))
+        } else {
+            self.write_register(Register::P1cr, clock & 0xF)
+        }
+    }
+
+    /// Enables or disables PWM1, which drives the backlight on Adafruit boards.
+    pub fn backlight_on(&mut self, on: bool) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.pwm1_config(on, cmds::PwmClk::Div1024 as u8)
+    }
+
+    /// Sets the backlight brightness as a percentage. Values above 100 are treated as 100.
+    pub fn set_backlight(&mut self, percent: u8) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let percent = percent.min(100) as u16;
+        self.pwm1_out((percent * 255 / 100) as u8)
+    }
+
+    /// Steps the backlight from `from` to `to` percent one percent at a time, waiting
+    /// `step_ms` milliseconds between steps.
+    pub fn fade_backlight<D: DelayMs<u8>>(
+        &mut self,
+        from: u8,
+        to: u8,
+        step_ms: u8,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (from, to) = (from.min(100), to.min(100));
+        let mut level = from;
+        self.set_backlight(level)?;
+        while level != to {
+            delay.delay_ms(step_ms);
+            level = if level < to { level + 1 } else { level - 1 };
+            self.set_backlight(level)?;
+        }
+        Ok(())
+    }
+
+    /// Fades the backlight from `from` to `to` percent in `steps` evenly sized steps, waiting
+    /// `step_ms` milliseconds between them.
+    ///
+    /// Unlike `fade_backlight`, which always moves one percent at a time, this fixes how many
+    /// steps the fade takes, so its duration is `steps * step_ms` regardless of the distance.
+    /// With `steps` of 0 the backlight jumps straight to `to`.
+    pub fn fade_backlight_steps<D: DelayMs<u8>>(
+        &mut self,
+        from: u8,
+        to: u8,
+        steps: u8,
+        step_ms: u8,
+        delay: &mut D,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (from, to) = (from.min(100) as i16, to.min(100) as i16);
+        if steps == 0 {
+            return self.set_backlight(to as u8);
+        }
+        self.set_backlight(from as u8)?;
+        for step in 1..=steps as i16 {
+            delay.delay_ms(step_ms);
+            self.set_backlight((from + (to - from) * step / steps as i16) as u8)?;
+        }
+        Ok(())
+    }
+
+    pub fn pwm2_out(&mut self, pulse: u8) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::P2dcr, pulse)
+    }
+    pub fn pwm2_config(
+        &mut self,
+        on: bool,
+        clock: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if on {
+            self.write_register(Register::P2cr, cmds::P2cr::Enable as u8 | (clock & 0xF))
+        } else {
+            self.write_register(Register::P2cr, clock & 0xF)
+        }
+    }
+
+    /// Rotates the drawing coordinate frame.
+    ///
+    /// Cursor positions, points, shapes, `bte_solid_fill` regions, touch readings, and the
+    /// embedded-graphics size are all expressed in the rotated frame, and the memory write
+    /// direction is adjusted so streamed pixels run along the rotated x axis. Text mode, the
+    /// other BTE operations, and scrolling address display memory directly and are not
+    /// rotated.
+    pub fn set_rotation(
+        &mut self,
+        rotation: Rotation,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let direction = match rotation {
+            Rotation::Rotate0 => WriteDirection::LeftRightTopDown,
+            Rotation::Rotate90 => WriteDirection::TopDownLeftRight,
+            Rotation::Rotate180 => WriteDirection::RightLeftTopDown,
+            Rotation::Rotate270 => WriteDirection::DownTopLeftRight,
+        };
+        self.set_write_direction(direction)?;
+        self.rotation = rotation;
+        Ok(())
+    }
+
+    /// Sets the order pixel writes fill memory in, for both writes and reads.
+    ///
+    /// This changes how `push_pixels` and streamed images lay out their data, e.g. writing a
+    /// row right to left to mirror it without reordering the data. `set_rotation` picks the
+    /// direction matching its frame, so call this after it; the rotated drawing methods
+    /// assume the direction `set_rotation` chose.
+    pub fn set_write_direction(
+        &mut self,
+        direction: WriteDirection,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let direction = match direction {
+            WriteDirection::LeftRightTopDown => cmds::Mwcr0Dir::LeftRightTopDown as u8,
+            WriteDirection::RightLeftTopDown => cmds::Mwcr0Dir::RightLeftTopDown as u8,
+            WriteDirection::TopDownLeftRight => cmds::Mwcr0Dir::TopDownLeftRight as u8,
+            WriteDirection::DownTopLeftRight => cmds::Mwcr0Dir::DownTopLeftRight as u8,
+        };
+        let tmp = self.read_register(Register::Mwcr0)?;
+        block!(self.write_data((tmp & !(cmds::Mwcr0::DirMask as u8)) | direction))?;
+        // The read direction uses the same encoding two bits lower.
+        let tmp = self.read_register(Register::Mrcd)?;
+        block!(self.write_data((tmp & !(cmds::Mrcd::DirMask as u8)) | (direction >> 2)))?;
+        Ok(())
+    }
+
+    /// The display dimensions as seen from the rotated coordinate frame.
+    fn rotated_dims(&self) -> (u32, u32) {
+        let (width, height) = self.dims;
+        match self.rotation {
+            Rotation::Rotate0 | Rotation::Rotate180 => (width, height),
+            Rotation::Rotate90 | Rotation::Rotate270 => (height, width),
+        }
+    }
+
+    /// Maps horizontal and vertical ellipse axes in the rotated frame to the native frame.
+    fn to_hw_axes(&self, horizontal: u16, vertical: u16) -> (u16, u16) {
+        match self.rotation {
+            Rotation::Rotate0 | Rotation::Rotate180 => (horizontal, vertical),
+            Rotation::Rotate90 | Rotation::Rotate270 => (vertical, horizontal),
+        }
+    }
+
+    /// Maps a coordinate in the rotated frame to the panel's native frame.
+    fn to_hw(&self, (x, y): Coord) -> Coord {
+        let (width, height) = (self.dims.0 as i16, self.dims.1 as i16);
+        match self.rotation {
+            Rotation::Rotate0 => (x, y),
+            Rotation::Rotate90 => (width - 1 - y, x),
+            Rotation::Rotate180 => (width - 1 - x, height - 1 - y),
+            Rotation::Rotate270 => (y, height - 1 - x),
+        }
+    }
+
+    /// Rejects cursor positions, in hardware coordinates, that fall outside the panel rather
+    /// than letting the chip mask off their high bits and wrap around.
+    #[cfg(feature = "checked-coords")]
+    fn check_bounds(&self, (x, y): Coord) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (width, height) = self.dims;
+        if x < 0 || y < 0 || x as u32 >= width || y as u32 >= height {
+            return Err(Error::OutOfBounds);
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "checked-coords"))]
+    fn check_bounds(&self, _: Coord) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        Ok(())
+    }
+
+    /// Maps a coordinate in the panel's native frame back to the rotated frame.
+    fn hw_to_rotated(&self, (x, y): Coord) -> Coord {
+        let (width, height) = (self.dims.0 as i16, self.dims.1 as i16);
+        match self.rotation {
+            Rotation::Rotate0 => (x, y),
+            Rotation::Rotate90 => (y, width - 1 - x),
+            Rotation::Rotate180 => (width - 1 - x, height - 1 - y),
+            Rotation::Rotate270 => (height - 1 - y, x),
+        }
+    }
+
+    /// Enables text mode
+    ///
+    /// Glyphs come from the font selected with `set_internal_font` or `set_external_font`,
+    /// which defaults to the internal ROM font.
+    pub fn text_mode(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
         match self.mode {
-            Mode::Graphics => Ok(()),
-            Mode::Text => {
+            Mode::Text => Ok(()),
+            Mode::Graphics => {
                 let tmp = self.read_register(Register::Mwcr0)?;
-                block!(self.write_data(tmp & !(cmds::Mwcr0::TxtMode as u8)))?;
-                self.mode = Mode::Graphics;
+                block!(self.write_data(tmp | cmds::Mwcr0::TxtMode as u8))?;
+                self.apply_font()?;
+                self.mode = Mode::Text;
+                if let Some((top_left, bottom_right)) = self.text_window {
+                    self.write_window_registers(top_left, bottom_right)?;
+                }
+
                 Ok(())
             }
         }
     }
 
-    /// Low-level function to push a raw chunk of pixel data.
-    pub fn push_pixels(&mut self, num_pixels: u32, color: u16) -> Result<(), SpiError<SPI>> {
-        block!(self.write_command(Register::Mrwc as u8))?;
-        self.cs.set_low().ok().unwrap();
-        self.spi_send(Command::DataWrite as u8)?;
-        for _ in 0..num_pixels {
-            self.spi_send((color >> 8) as u8)?;
-            self.spi_send(color as u8)?;
+    /// Selects the internal ROM font for text mode.
+    pub fn set_internal_font(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.text_settings.font = FontSource::Internal;
+        self.apply_font()
+    }
+
+    /// Selects an external serial font ROM for text mode, e.g. for CJK glyphs.
+    ///
+    /// With `FontEncoding::Unicode`, text written through `fmt::Write` is sent as UCS-2 so
+    /// ordinary Rust strings render directly. Other multi-byte encodings expect their bytes to
+    /// already be in that encoding.
+    pub fn set_external_font(
+        &mut self,
+        chip: FontRom,
+        encoding: FontEncoding,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.text_settings.font = FontSource::External(chip, encoding);
+        self.apply_font()
+    }
+
+    /// Writes the font source registers for the currently selected font.
+    fn apply_font(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        match self.text_settings.font {
+            FontSource::Internal => {
+                let tmp = self.read_register(Register::Fncr0)?;
+                block!(self.write_data(
+                    tmp & !(cmds::Fncr0::CgramSelect as u8 | cmds::Fncr0::ExternalCgrom as u8)
+                ))?;
+
+                // Clear serial font ROM settings
+                self.write_register(Register::Sfrs, 0x00)?;
+            }
+            FontSource::External(chip, encoding) => {
+                self.write_register(Register::Sroc, cmds::Sroc::FontRom as u8)?;
+                self.write_register(Register::Sfclr, cmds::Sfclr::SysClkDiv4 as u8)?;
+
+                let tmp = self.read_register(Register::Fncr0)?;
+                block!(self.write_data(
+                    (tmp & !(cmds::Fncr0::CgramSelect as u8)) | cmds::Fncr0::ExternalCgrom as u8
+                ))?;
+
+                self.write_register(Register::Sfrs, chip as u8 | encoding as u8)?;
+
+                let size = match chip {
+                    FontRom::GT21L16T1W | FontRom::GT30L16U2W => cmds::Fwtsr::Size16 as u8,
+                    FontRom::GT30L24T3Y | FontRom::GT30L24M1Z => cmds::Fwtsr::Size24 as u8,
+                    FontRom::GT30L32S4W => cmds::Fwtsr::Size32 as u8,
+                };
+                let tmp = self.read_register(Register::Fwtsr)?;
+                block!(self.write_data((tmp & !(cmds::Fwtsr::SizeMask as u8)) | size))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the same enlargement on both axes; see `set_text_scale_xy`.
+    pub fn set_text_scale(&mut self, scale: u8) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.set_text_scale_xy(scale, scale)
+    }
+
+    /// Sets horizontal and vertical text enlargement independently, each 0..=3 for 1x to 4x.
+    pub fn set_text_scale_xy(
+        &mut self,
+        x_scale: u8,
+        y_scale: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let options = FontOptions {
+            enlarge_x: x_scale,
+            enlarge_y: y_scale,
+            ..self.text_settings.options
+        };
+        self.set_font_options(options)
+    }
+
+    /// Width and height of a half-width glyph in the active font, before enlargement.
+    fn glyph_size(&self) -> (u16, u16) {
+        match self.text_settings.font {
+            FontSource::Internal => (8, 16),
+            FontSource::External(FontRom::GT21L16T1W, _)
+            | FontSource::External(FontRom::GT30L16U2W, _) => (8, 16),
+            FontSource::External(FontRom::GT30L24T3Y, _)
+            | FontSource::External(FontRom::GT30L24M1Z, _) => (12, 24),
+            FontSource::External(FontRom::GT30L32S4W, _) => (16, 32),
+        }
+    }
+
+    /// The width in pixels `s` will take up when written with the current font and
+    /// enlargement. With an external font ROM, non-ASCII characters are full-width glyphs,
+    /// twice as wide as ASCII ones.
+    pub fn text_width(&self, s: &str) -> u16 {
+        let (width, height) = self.glyph_size();
+        let full_width = match self.text_settings.font {
+            FontSource::Internal => width,
+            FontSource::External(..) => height,
+        };
+        let unscaled = s.chars().fold(0_u16, |total, c| {
+            total.saturating_add(if c.is_ascii() { width } else { full_width })
+        });
+        unscaled.saturating_mul(self.text_settings.options.enlarge_x as u16 + 1)
+    }
+
+    /// The height in pixels of a line of text with the current font and enlargement.
+    pub fn line_height(&self) -> u16 {
+        let (_, height) = self.glyph_size();
+        height * (self.text_settings.options.enlarge_y as u16 + 1)
+    }
+
+    /// Applies character set, enlargement, rotation, and transparency settings for text.
+    ///
+    /// Returns `Error::TextScaleOutOfRange` if either enlargement is above 3.
+    pub fn set_font_options(
+        &mut self,
+        options: FontOptions,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if options.enlarge_x > 3 || options.enlarge_y > 3 {
+            return Err(Error::TextScaleOutOfRange);
+        }
+
+        let tmp = self.read_register(Register::Fncr0)?;
+        block!(self.write_data((tmp & !(cmds::Fncr0::SetMask as u8)) | options.set as u8))?;
+
+        let mut bits = (options.enlarge_x << 2) | options.enlarge_y;
+        if options.rotate_90 {
+            bits |= cmds::Fncr1::Rotate90 as u8;
+        }
+        if options.transparent {
+            bits |= cmds::Fncr1::Transparent as u8;
+        }
+        let mask = cmds::Fncr1::EnlargeMask as u8
+            | cmds::Fncr1::Rotate90 as u8
+            | cmds::Fncr1::Transparent as u8;
+        self.update_fncr1(mask, bits)?;
+
+        self.text_settings.options = options;
+
+        Ok(())
+    }
+
+    /// Draws text with a transparent background, leaving whatever is underneath the glyphs
+    /// visible, or with the text background color.
+    ///
+    /// The register is only written when the setting actually changes.
+    pub fn set_text_transparent(
+        &mut self,
+        transparent: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let bit = cmds::Fncr1::Transparent as u8;
+        self.update_fncr1(bit, if transparent { bit } else { 0 })?;
+        self.text_settings.options.transparent = transparent;
+        Ok(())
+    }
+
+    /// Replaces the `mask` bits of the FontOptions register with `bits`, using the cached
+    /// register value rather than reading it back and skipping the write if nothing changed.
+    fn update_fncr1(
+        &mut self,
+        mask: u8,
+        bits: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let value = (self.text_settings.fncr1 & !mask) | bits;
+        if value != self.text_settings.fncr1 {
+            self.write_register(Register::FontOptions, value)?;
+            self.text_settings.fncr1 = value;
+        }
+        Ok(())
+    }
+
+    /// Shows the hardware text cursor and blinks it every `blink_frames + 1` display frames.
+    ///
+    /// The cursor sits at the text write position, so it follows along as text is written
+    /// and needs no further attention from the host.
+    pub fn enable_text_cursor(
+        &mut self,
+        blink_frames: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::Btcr, blink_frames)?;
+        let tmp = self.read_register(Register::Mwcr0)?;
+        block!(self
+            .write_data(tmp | cmds::Mwcr0::CursorVisible as u8 | cmds::Mwcr0::CursorBlink as u8))?;
+        Ok(())
+    }
+
+    /// Shows or hides the hardware text cursor.
+    pub fn set_text_cursor_visible(
+        &mut self,
+        visible: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let tmp = self.read_register(Register::Mwcr0)?;
+        if visible {
+            block!(self.write_data(tmp | cmds::Mwcr0::CursorVisible as u8))?;
+        } else {
+            block!(self.write_data(tmp & !(cmds::Mwcr0::CursorVisible as u8)))?;
+        }
+        Ok(())
+    }
+
+    /// Turns blinking of the text cursor on or off, leaving it visible either way.
+    pub fn set_cursor_blink(&mut self, on: bool) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let tmp = self.read_register(Register::Mwcr0)?;
+        if on {
+            block!(self.write_data(tmp | cmds::Mwcr0::CursorBlink as u8))?;
+        } else {
+            block!(self.write_data(tmp & !(cmds::Mwcr0::CursorBlink as u8)))?;
+        }
+        Ok(())
+    }
+
+    /// Sets the text cursor size in pixels, each 1..=32. A full-height narrow cursor makes
+    /// an I-beam; a wide, short one makes an underline.
+    pub fn set_text_cursor_size(
+        &mut self,
+        width: u8,
+        height: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::Curhs, width.clamp(1, 32) - 1)?;
+        self.write_register(Register::Curvs, height.clamp(1, 32) - 1)
+    }
+
+    /// Enables graphics mode
+    pub fn graphics_mode(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        match self.mode {
+            Mode::Graphics => Ok(()),
+            Mode::Text => {
+                let tmp = self.read_register(Register::Mwcr0)?;
+                block!(self.write_data(tmp & !(cmds::Mwcr0::TxtMode as u8)))?;
+                self.mode = Mode::Graphics;
+                if self.text_window.is_some() {
+                    let (top_left, bottom_right) = self.window;
+                    self.write_window_registers(top_left, bottom_right)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Low-level function to push a raw chunk of pixel data.
+    pub fn push_pixels(
+        &mut self,
+        num_pixels: u32,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        block!(self.write_command(Register::Mrwc as u8))?;
+        let depth = self.color_depth;
+        let order = self.byte_order;
+        self.spi
+            .write_data_iter((0..num_pixels).flat_map(|_| pixel_bytes(depth, order, color)))?;
+        Ok(())
+    }
+
+    /// Low-level function to stream a run of differently colored pixels starting at the
+    /// current cursor.
+    pub fn push_pixel_slice(
+        &mut self,
+        colors: &[u16],
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        block!(self.write_command(Register::Mrwc as u8))?;
+        let depth = self.color_depth;
+        let order = self.byte_order;
+        self.spi.write_data_iter(
+            colors
+                .iter()
+                .flat_map(|&color| pixel_bytes(depth, order, color)),
+        )?;
+        Ok(())
+    }
+
+    /// Writes a buffered run of pixels, first moving the cursor to `seek` if the run doesn't
+    /// continue from where the previous one left off.
+    #[cfg(feature = "embedded-graphics")]
+    fn flush_run(
+        &mut self,
+        seek: Option<Point>,
+        colors: &[u16],
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if colors.is_empty() {
+            return Ok(());
+        }
+        if let Some(point) = seek {
+            self.set_graphics_cursor(to_coord(point))?;
+        }
+        self.push_pixel_slice(colors)
+    }
+
+    fn set_read_cursor(
+        &mut self,
+        position: Coord,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (x, y) = self.to_hw(position);
+        self.write_register(Register::RCurH0, x as u8)?;
+        self.write_register(Register::RCurH1, (x >> 8) as u8)?;
+        self.write_register(Register::RCurV0, y as u8)?;
+        self.write_register(Register::RCurV1, (y >> 8) as u8)?;
+        Ok(())
+    }
+
+    /// Starts a memory read at `position`, including the dummy read the chip needs before
+    /// valid pixel data comes out.
+    fn start_memory_read(
+        &mut self,
+        position: Coord,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.set_read_cursor(position)?;
+        block!(self.write_command(Register::Mrwc as u8))?;
+        block!(self.read_data())?;
+        Ok(())
+    }
+
+    fn read_pixel_data(&mut self) -> Result<u16, Error<SpiError<SPI>, PinError<P>>> {
+        match self.color_depth {
+            ColorDepth::Bpp16 => {
+                let high = block!(self.read_data())? as u16;
+                let low = block!(self.read_data())? as u16;
+                Ok((high << 8) | low)
+            }
+            ColorDepth::Bpp8 => Ok(rgb332_to_rgb565(block!(self.read_data())?)),
+        }
+    }
+
+    /// Reads back the color of a single pixel, in the same RGB565 format `draw_point` accepts.
+    pub fn read_pixel(&mut self, coord: Coord) -> Result<u16, Error<SpiError<SPI>, PinError<P>>> {
+        self.start_memory_read(coord)?;
+        self.read_pixel_data()
+    }
+
+    /// Reads back a `width` x `height` region row by row into `buf`, which must hold exactly
+    /// `width * height` pixels.
+    pub fn read_region(
+        &mut self,
+        (x, y): Coord,
+        width: u16,
+        height: u16,
+        buf: &mut [u16],
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if buf.len() != width as usize * height as usize {
+            return Err(Error::InvalidBufferLength);
+        }
+        if width == 0 {
+            return Ok(());
+        }
+        for (row, pixels) in buf.chunks_mut(width as usize).enumerate() {
+            self.start_memory_read((x, y + row as i16))?;
+            for pixel in pixels.iter_mut() {
+                *pixel = self.read_pixel_data()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves the cursor for whichever mode is active. Use `set_graphics_cursor` or
+    /// `set_text_cursor` to target a specific one regardless of mode.
+    pub fn set_cursor(
+        &mut self,
+        new_position: Coord,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        match self.mode {
+            Mode::Graphics => self.set_graphics_cursor(new_position),
+            Mode::Text => self.set_text_cursor(new_position),
+        }
+    }
+
+    /// Moves the graphics memory write cursor, in the rotated coordinate frame.
+    pub fn set_graphics_cursor(
+        &mut self,
+        new_position: Coord,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (x, y) = self.to_hw(new_position);
+        self.check_bounds((x, y))?;
+        self.write_register(Register::CurH0, x as u8)?;
+        self.write_register(Register::CurH1, (x >> 8) as u8)?;
+        self.write_register(Register::CurV0, y as u8)?;
+        self.write_register(Register::CurV1, (y >> 8) as u8)?;
+        self.gfx_settings.cursor = new_position;
+        Ok(())
+    }
+
+    /// Moves the text cursor, where the next character will be written.
+    pub fn set_text_cursor(
+        &mut self,
+        new_position: Coord,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (x, y) = new_position;
+        self.check_bounds((x, y))?;
+        self.write_register(Register::TextX0, x as u8)?;
+        self.write_register(Register::TextX1, (x >> 8) as u8)?;
+        self.write_register(Register::TextY0, y as u8)?;
+        self.write_register(Register::TextY1, (y >> 8) as u8)?;
+        self.text_settings.cursor = new_position;
+        Ok(())
+    }
+
+    /// Returns the text state the driver is tracking, without reading the chip.
+    pub fn text_state(&self) -> TextState {
+        let settings = &self.text_settings;
+        TextState {
+            cursor: settings.cursor,
+            fg_color: settings.fg_color,
+            bg_color: settings.bg_color,
+            options: settings.options,
+            font: settings.font,
+        }
+    }
+
+    /// Reapplies a text state previously returned by `text_state`.
+    ///
+    /// Text mode is entered for the duration, and left again afterwards if the display was
+    /// in graphics mode.
+    pub fn restore_text_state(
+        &mut self,
+        state: TextState,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let previous = self.mode;
+        self.text_mode()?;
+        if self.text_settings.font != state.font {
+            self.text_settings.font = state.font;
+            self.apply_font()?;
+        }
+        self.set_font_options(state.options)?;
+        self.set_colors(state.fg_color, state.bg_color)?;
+        self.set_text_cursor(state.cursor)?;
+        if let Mode::Graphics = previous {
+            self.graphics_mode()?;
+        }
+        Ok(())
+    }
+
+    /// Writes `s` at `position` in the given colors, switching to text mode for the duration
+    /// and back to graphics mode afterwards if that's where the display was.
+    ///
+    /// A `bg_color` of `None` draws the text with a transparent background. Note that the
+    /// foreground color is shared with graphics mode, so later shapes drawn without setting a
+    /// color will use `fg_color`.
+    pub fn draw_text<C: DisplayColor>(
+        &mut self,
+        position: Coord,
+        s: &str,
+        fg_color: C,
+        bg_color: Option<C>,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (fg_color, bg_color) = (fg_color.into_raw(), bg_color.map(C::into_raw));
+        let previous = self.mode;
+        self.text_mode()?;
+        self.set_text_cursor(position)?;
+        self.set_colors(fg_color, bg_color)?;
+        self.write_text(s)?;
+        if let Mode::Graphics = previous {
+            self.graphics_mode()?;
+        }
+        Ok(())
+    }
+
+    /// Draws the single character `c` at `position`, in the given colors and text scale
+    /// (0..=3 for 1x to 4x), switching to text mode for the duration like `draw_text`.
+    ///
+    /// With a byte-encoded font, characters the font can't represent are drawn as `?`:
+    /// anything outside ASCII, except for Latin-1 characters with the internal font's
+    /// ISO 8859-1 set. The scale stays in effect afterwards.
+    pub fn draw_char<C: DisplayColor>(
+        &mut self,
+        position: Coord,
+        c: char,
+        fg_color: C,
+        bg_color: Option<C>,
+        scale: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (fg_color, bg_color) = (fg_color.into_raw(), bg_color.map(C::into_raw));
+        let previous = self.mode;
+        self.text_mode()?;
+        self.set_text_scale(scale)?;
+        self.set_text_cursor(position)?;
+        self.set_colors(fg_color, bg_color)?;
+        if let FontSource::External(_, FontEncoding::Unicode) = self.text_settings.font {
+            self.write_glyphs(c.encode_utf8(&mut [0; 4]))?;
+        } else {
+            let code = c as u32;
+            let latin1 = self.text_settings.font == FontSource::Internal
+                && self.text_settings.options.set == FontSet::Iso8859_1;
+            let byte = if code < 0x80 || (latin1 && code <= 0xFF) {
+                code as u8
+            } else {
+                b'?'
+            };
+            block!(self.write_command(Register::Mrwc as u8))?;
+            block!(self.write_data(byte))?;
+        }
+        self.read_text_cursor()?;
+        if let Mode::Graphics = previous {
+            self.graphics_mode()?;
+        }
+        Ok(())
+    }
+
+    /// Sends `s` to display memory at the text cursor. Assumes text mode is active.
+    ///
+    /// `\n` starts a new line at the left edge of the active window and `\r` returns to the
+    /// left edge of the current line. With word wrap enabled, a word that would run past the
+    /// right edge of the window is moved to the next line.
+    fn write_text(&mut self, s: &str) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.text_newline()?;
+            }
+            for (j, piece) in line.split('\r').enumerate() {
+                if j > 0 {
+                    let (_, y) = self.read_text_cursor()?;
+                    self.set_text_cursor(((self.text_bounds().0).0, y))?;
+                }
+                if self.text_settings.word_wrap {
+                    for word in piece.split_inclusive(' ') {
+                        let (x, _) = self.read_text_cursor()?;
+                        let ((left, _), (right, _)) = self.text_bounds();
+                        let width = self.text_width(word.trim_end()) as i16;
+                        if x != left && x + width > right + 1 {
+                            self.text_newline()?;
+                        }
+                        self.write_glyphs(word)?;
+                    }
+                } else {
+                    self.write_glyphs(piece)?;
+                }
+            }
+        }
+        // Keep the cached cursor in step with where the chip left it.
+        self.read_text_cursor()?;
+        Ok(())
+    }
+
+    /// Turns word wrapping of text written through `fmt::Write` and `draw_text` on or off.
+    pub fn set_word_wrap(&mut self, wrap: bool) {
+        self.text_settings.word_wrap = wrap;
+    }
+
+    /// Moves the text cursor to the start of the next line in the text window.
+    fn text_newline(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (_, y) = self.read_text_cursor()?;
+        let line_height = self.line_height() as i16;
+        self.set_text_cursor(((self.text_bounds().0).0, y + line_height))
+    }
+
+    /// Confines text to the rectangle between `top_left` and `bottom_right`, inclusive, in
+    /// hardware coordinates like the text cursor.
+    ///
+    /// The chip's own line wrapping, `\n` and `\r` handling, and word wrap all use this window
+    /// instead of the active window. Since the RA8875 has a single active window, the text
+    /// window is swapped in on entering text mode and the active window restored on leaving.
+    pub fn set_text_window(
+        &mut self,
+        (ax, ay): Coord,
+        (bx, by): Coord,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let window = ((ax.min(bx), ay.min(by)), (ax.max(bx), ay.max(by)));
+        self.text_window = Some(window);
+        if let Mode::Text = self.mode {
+            self.write_window_registers(window.0, window.1)?;
+        }
+        Ok(())
+    }
+
+    /// Removes the text window, so text uses the active window again.
+    pub fn reset_text_window(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if self.text_window.take().is_some() {
+            if let Mode::Text = self.mode {
+                let (top_left, bottom_right) = self.window;
+                self.write_window_registers(top_left, bottom_right)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The window text wraps within, in hardware coordinates.
+    fn text_bounds(&self) -> (Coord, Coord) {
+        self.text_window.unwrap_or(self.window)
+    }
+
+    /// Reads back the text cursor, which the chip advances as characters are written, and
+    /// updates the cached position.
+    fn read_text_cursor(&mut self) -> Result<Coord, Error<SpiError<SPI>, PinError<P>>> {
+        let x0 = self.read_register(Register::TextX0)? as i16;
+        let x1 = self.read_register(Register::TextX1)? as i16;
+        let y0 = self.read_register(Register::TextY0)? as i16;
+        let y1 = self.read_register(Register::TextY1)? as i16;
+        self.text_settings.cursor = ((x1 << 8) | x0, (y1 << 8) | y0);
+        Ok(self.text_settings.cursor)
+    }
+
+    /// Reads the cursor for the current mode back from the chip, which advances it as text
+    /// and pixels are written, and updates the position `cursor` reports.
+    ///
+    /// The graphics cursor is returned in the rotated frame, the text cursor in hardware
+    /// coordinates, matching `set_graphics_cursor` and `set_text_cursor`.
+    pub fn read_cursor(&mut self) -> Result<Coord, Error<SpiError<SPI>, PinError<P>>> {
+        match self.mode {
+            Mode::Text => self.read_text_cursor(),
+            Mode::Graphics => {
+                let x0 = self.read_register(Register::CurH0)? as i16;
+                let x1 = self.read_register(Register::CurH1)? as i16;
+                let y0 = self.read_register(Register::CurV0)? as i16;
+                let y1 = self.read_register(Register::CurV1)? as i16;
+                self.gfx_settings.cursor = self.hw_to_rotated(((x1 << 8) | x0, (y1 << 8) | y0));
+                Ok(self.gfx_settings.cursor)
+            }
+        }
+    }
+
+    /// Sends the encoded glyphs of `s` to display memory at the text cursor.
+    fn write_glyphs(&mut self, s: &str) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if s.is_empty() {
+            return Ok(());
+        }
+        block!(self.write_command(Register::Mrwc as u8))?;
+        match self.text_settings.font {
+            FontSource::External(_, FontEncoding::Unicode) => {
+                for c in s.chars() {
+                    let code = c as u32;
+                    if code < 0x80 {
+                        block!(self.write_data(code as u8))?;
+                    } else {
+                        let code = if code > 0xFFFF { '?' as u32 } else { code };
+                        block!(self.write_data((code >> 8) as u8))?;
+                        block!(self.write_data(code as u8))?;
+                    }
+                }
+            }
+            _ => {
+                for c in s.as_bytes() {
+                    block!(self.write_data(*c))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the colors for the current display mode. If `bg_color` is `None`, then a transparent
+    /// background will be used.
+    fn set_colors(
+        &mut self,
+        fg_color: u16,
+        bg_color: Option<u16>,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (r, g, b) = color_components(self.color_depth, fg_color);
+        match self.mode {
+            Mode::Graphics => {
+                self.write_register(Register::Color0, r)?;
+                self.write_register(Register::Color1, g)?;
+                self.write_register(Register::Color2, b)?;
+                Ok(())
+            }
+            Mode::Text => {
+                self.write_register(Register::Color0, r)?;
+                self.write_register(Register::Color1, g)?;
+                self.write_register(Register::Color2, b)?;
+
+                match bg_color {
+                    Some(color) => {
+                        let (r, g, b) = color_components(self.color_depth, color);
+                        self.write_register(Register::TextBg0, r)?;
+                        self.write_register(Register::TextBg1, g)?;
+                        self.write_register(Register::TextBg2, b)?;
+                        self.set_text_transparent(false)?;
+                    }
+                    None => self.set_text_transparent(true)?,
+                }
+
+                self.text_settings.fg_color = fg_color;
+                self.text_settings.bg_color = bg_color;
+
+                Ok(())
+            }
+        }
+    }
+
+    fn fill_rect(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        block!(self.write_command(Register::Dcr as u8))?;
+        block!(self.write_data(cmds::Dcr::DRAWSQUARE as u8))?;
+        block!(self.write_data(
+            cmds::Dcr::LINESQUTRI_START as u8 | cmds::Dcr::FILL as u8 | cmds::Dcr::DRAWSQUARE as u8
+        ))?;
+        Ok(())
+    }
+
+    /// Draw a single `color` colored point at coordinate `coord`.
+    pub fn draw_point(
+        &mut self,
+        coord: Coord,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        self.set_cursor(coord)?;
+        block!(self.write_command(Register::Mrwc as u8))?;
+        self.spi
+            .write_data_iter(pixel_bytes(self.color_depth, self.byte_order, color))?;
+        Ok(())
+    }
+
+    pub fn draw_line(
+        &mut self,
+        start: Coord,
+        end: Coord,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        let (x0, y0) = self.to_hw(start);
+        self.write_register(Register::ShapeStartX0, x0 as u8)?;
+        self.write_register(Register::ShapeStartX1, (x0 >> 8) as u8)?;
+        self.write_register(Register::ShapeStartY0, y0 as u8)?;
+        self.write_register(Register::ShapeStartY1, (y0 >> 8) as u8)?;
+        let (x1, y1) = self.to_hw(end);
+        self.write_register(Register::ShapeEndX0, x1 as u8)?;
+        self.write_register(Register::ShapeEndX1, (x1 >> 8) as u8)?;
+        self.write_register(Register::ShapeEndY0, y1 as u8)?;
+        self.write_register(Register::ShapeEndY1, (y1 >> 8) as u8)?;
+        self.set_colors(color, None)?;
+        self.write_register(Register::Dcr, 0x80)?;
+        // Wait for command to finish
+        self.wait_for_draw_complete(Register::Dcr, 0x80)?;
+        Ok(())
+    }
+
+    /// Draws a line `width` pixels thick, centered on the line from `start` to `end`.
+    ///
+    /// Horizontal and vertical lines are drawn as a single filled rectangle. Other lines are
+    /// built from parallel 1px lines offset along whichever axis the line moves least in,
+    /// which keeps neighbouring lines touching so the stroke has no gaps.
+    pub fn draw_line_width(
+        &mut self,
+        start: Coord,
+        end: Coord,
+        width: u16,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        if width <= 1 {
+            return self.draw_line(start, end, color);
+        }
+        let before = ((width - 1) / 2) as i16;
+        let after = (width / 2) as i16;
+        let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+
+        if dy == 0 {
+            return self.draw_rect(
+                (start.0, start.1 - before),
+                (end.0, end.1 + after),
+                color,
+                true,
+            );
+        }
+        if dx == 0 {
+            return self.draw_rect(
+                (start.0 - before, start.1),
+                (end.0 + after, end.1),
+                color,
+                true,
+            );
+        }
+
+        let mostly_horizontal = dx.abs() >= dy.abs();
+        for offset in -before..=after {
+            let (ox, oy) = if mostly_horizontal {
+                (0, offset)
+            } else {
+                (offset, 0)
+            };
+            self.draw_line(
+                (start.0 + ox, start.1 + oy),
+                (end.0 + ox, end.1 + oy),
+                color,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Draws a dashed line from `start` to `end`, alternating `dash_len` drawn pixels with
+    /// `gap_len` skipped ones.
+    ///
+    /// Dashes are measured in steps along the Bresenham path, so diagonal lines get the same
+    /// pattern as axis-aligned ones. Each dash is drawn with the hardware line command.
+    pub fn draw_dashed_line(
+        &mut self,
+        start: Coord,
+        end: Coord,
+        color: impl DisplayColor,
+        dash_len: u16,
+        gap_len: u16,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        if gap_len == 0 {
+            return self.draw_line(start, end, color);
+        }
+        if dash_len == 0 {
+            return Ok(());
+        }
+        let period = dash_len as u32 + gap_len as u32;
+
+        let (mut x, mut y) = (start.0 as i32, start.1 as i32);
+        let (x1, y1) = (end.0 as i32, end.1 as i32);
+        let (dx, dy) = ((x1 - x).abs(), -(y1 - y).abs());
+        let (sx, sy) = (if x < x1 { 1 } else { -1 }, if y < y1 { 1 } else { -1 });
+        let mut err = dx + dy;
+        let mut step = 0_u32;
+        let mut dash: Option<(Coord, Coord)> = None;
+        loop {
+            let point = (x as i16, y as i16);
+            if step % period < dash_len as u32 {
+                dash = Some(dash.map_or((point, point), |(from, _)| (from, point)));
+            } else if let Some((from, to)) = dash.take() {
+                self.draw_line(from, to, color)?;
+            }
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+            step += 1;
+        }
+        if let Some((from, to)) = dash {
+            self.draw_line(from, to, color)?;
+        }
+        Ok(())
+    }
+
+    pub fn draw_vline(
+        &mut self,
+        start: Coord,
+        height: i16,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        self.draw_line(start, (start.0, start.1 + height), color)
+    }
+
+    pub fn draw_hline(
+        &mut self,
+        start: Coord,
+        width: i16,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        self.draw_line(start, (start.0 + width, start.1), color)
+    }
+
+    /// Draws the rectangle with corners `top_left` and `bottom_right`, both inclusive, outlined
+    /// or filled.
+    ///
+    /// The corners may be given in either order; they're normalized after mapping into the
+    /// panel's native frame. Equal corners draw a single pixel.
+    pub fn draw_rect(
+        &mut self,
+        top_left: Coord,
+        bottom_right: Coord,
+        color: impl DisplayColor,
+        fill: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.start_rect(top_left, bottom_right, color.into_raw(), fill)?;
+        // Wait for command to finish
+        self.wait_for_draw_complete(Register::Dcr, 0x80)
+    }
+
+    /// Kicks off a rectangle draw without waiting for the chip to finish it.
+    fn start_rect(
+        &mut self,
+        top_left: Coord,
+        bottom_right: Coord,
+        color: u16,
+        fill: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        // Rotation can swap which corner is which, so normalize after mapping.
+        let (ax, ay) = self.to_hw(top_left);
+        let (bx, by) = self.to_hw(bottom_right);
+        self.start_rect_hw(
+            (ax.min(bx), ay.min(by)),
+            (ax.max(bx), ay.max(by)),
+            color,
+            fill,
+        )
+    }
+
+    /// Kicks off a rectangle draw between corners given in hardware coordinates.
+    fn start_rect_hw(
+        &mut self,
+        (x0, y0): Coord,
+        (x1, y1): Coord,
+        color: u16,
+        fill: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::ShapeStartX0, x0 as u8)?;
+        self.write_register(Register::ShapeStartX1, (x0 >> 8) as u8)?;
+        self.write_register(Register::ShapeStartY0, y0 as u8)?;
+        self.write_register(Register::ShapeStartY1, (y0 >> 8) as u8)?;
+        self.write_register(Register::ShapeEndX0, x1 as u8)?;
+        self.write_register(Register::ShapeEndX1, (x1 >> 8) as u8)?;
+        self.write_register(Register::ShapeEndY0, y1 as u8)?;
+        self.write_register(Register::ShapeEndY1, (y1 >> 8) as u8)?;
+        self.set_colors(color, None)?;
+        if fill {
+            self.write_register(Register::Dcr, 0xB0)
+        } else {
+            self.write_register(Register::Dcr, 0x90)
+        }
+    }
+
+    /// Fills the screen with `color`.
+    ///
+    /// Black, the usual clear between frames, takes a full-window memory clear instead of a
+    /// filled rectangle. Both run inside the chip without streaming pixels over SPI, which
+    /// for an 800x480 panel would be 768 KB, over half a second at 10 MHz. The memory clear
+    /// is the quicker of the two because it writes display memory directly, without the
+    /// drawing engine rasterizing the rectangle; both still take long enough that it's worth
+    /// clearing only once per frame. Black is zero at either color depth, so the fast path
+    /// works for both. Either way the whole screen is filled, whatever the active window.
+    pub fn fill_screen(
+        &mut self,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        if color == 0 {
+            return self.clear_to_black(cmds::Mclr::Start as u8);
+        }
+        let (width, height) = self.rotated_dims();
+        self.draw_rect((0, 0), (width as i16 - 1, height as i16 - 1), color, true)
+    }
+
+    /// Fills the active window set with `set_active_window` with `color`.
+    ///
+    /// Without a custom window this covers the whole screen, just like `fill_screen`.
+    pub fn clear_window(
+        &mut self,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (top_left, bottom_right) = self.window;
+        self.start_rect_hw(top_left, bottom_right, color.into_raw(), true)?;
+        self.wait_for_draw_complete(Register::Dcr, 0x80)
+    }
+
+    /// Starts filling the screen and returns without waiting for the fill to finish.
+    ///
+    /// Use `poll_draw` to find out when the chip is done, e.g. from a cooperative scheduler.
+    pub fn start_fill_screen(
+        &mut self,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (width, height) = self.rotated_dims();
+        self.start_rect(
+            (0, 0),
+            (width as i16 - 1, height as i16 - 1),
+            color.into_raw(),
+            true,
+        )
+    }
+
+    /// Starts filling the rectangle between `top_left` and `bottom_right` and returns without
+    /// waiting for the fill to finish. Poll for completion with `draw_busy` or `poll_draw`.
+    pub fn start_fill_rect(
+        &mut self,
+        top_left: Coord,
+        bottom_right: Coord,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.start_rect(top_left, bottom_right, color.into_raw(), true)
+    }
+
+    /// Whether the drawing engine is still busy with a shape, ellipse, or block transfer.
+    ///
+    /// The blocking draw methods wait on these same bits before returning; this lets a
+    /// non-blocking loop check on a draw started with `start_fill_rect` or
+    /// `start_fill_screen` while doing other work.
+    pub fn draw_busy(&mut self) -> Result<bool, Error<SpiError<SPI>, PinError<P>>> {
+        let shape = cmds::Dcr::LINESQUTRI_START as u8 | cmds::Dcr::CIRCLE_START as u8;
+        if self.read_register(Register::Dcr)? & shape != 0x00 {
+            return Ok(true);
+        }
+        let ellipse = cmds::DrawEllipseCR::DRAWSTART as u8;
+        if self.read_register(Register::DrawEllipseCR)? & ellipse != 0x00 {
+            return Ok(true);
+        }
+        Ok(self.read_register(Register::Becr0)? & cmds::Becr0::Enable as u8 != 0x00)
+    }
+
+    /// Returns `WouldBlock` while a draw started with `start_fill_rect` or
+    /// `start_fill_screen` is still in progress.
+    pub fn poll_draw(&mut self) -> nb::Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if self.draw_busy()? {
+            Err(nb::Error::WouldBlock)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Draws a ring of `color` between `inner_radius` and `outer_radius`, e.g. for a gauge
+    /// bezel.
+    ///
+    /// The chip can't leave the middle of a filled circle untouched, so the ring is drawn as
+    /// a filled outer circle with the hole filled in `hole_color` on top, usually the
+    /// background color. Radii follow `draw_circle`'s limits; an inner radius at or above the
+    /// outer radius draws just the outline of the outer circle.
+    pub fn draw_ring(
+        &mut self,
+        center: Coord,
+        outer_radius: i16,
+        inner_radius: i16,
+        color: impl DisplayColor,
+        hole_color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        if inner_radius >= outer_radius {
+            return self.draw_circle(center, outer_radius, color, false);
+        }
+        if !(0..=0xFF).contains(&inner_radius) {
+            return Err(Error::RadiusOutOfRange);
+        }
+        self.draw_circle(center, outer_radius, color, true)?;
+        self.draw_circle(center, inner_radius, hole_color, true)
+    }
+
+    /// Fills `rect` with a gradient from `start_color` to `end_color` along `direction`.
+    ///
+    /// The colors are blended with `blend_rgb565` and streamed into the rectangle as one
+    /// image. A vertical gradient blends one color per row and repeats it across the row; a
+    /// horizontal one blends each column's color as the row goes out. Only the part of `rect`
+    /// on the screen is drawn, with the gradient still spanning the whole of `rect`.
+    #[cfg(feature = "embedded-graphics")]
+    pub fn fill_gradient(
+        &mut self,
+        rect: &primitives::Rectangle,
+        start_color: impl DisplayColor,
+        end_color: impl DisplayColor,
+        direction: GradientDirection,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (start_color, end_color) = (start_color.into_raw(), end_color.into_raw());
+        let visible = rect.intersection(&primitives::Rectangle::new(Point::zero(), self.size()));
+        if visible.is_zero_sized() {
+            return Ok(());
+        }
+        // Offsets of the visible part within `rect`, so the colors line up with the whole.
+        let dx = (visible.top_left.x - rect.top_left.x) as u32;
+        let dy = (visible.top_left.y - rect.top_left.y) as u32;
+        let (width, height) = (visible.size.width as u16, visible.size.height as u16);
+        let steps = match direction {
+            GradientDirection::Horizontal => rect.size.width,
+            GradientDirection::Vertical => rect.size.height,
+        };
+        let blend = move |i: u32| {
+            let alpha = if steps > 1 {
+                (i as u64 * 255 / (steps as u64 - 1)) as u8
+            } else {
+                0
+            };
+            blend_rgb565(start_color, end_color, alpha)
+        };
+        let rows = (0..height as u32).flat_map(move |row| {
+            let row_color = match direction {
+                GradientDirection::Horizontal => None,
+                GradientDirection::Vertical => Some(blend(dy + row)),
+            };
+            (0..width as u32).map(move |column| row_color.unwrap_or_else(|| blend(dx + column)))
+        });
+        self.stream_region(to_coord(visible.top_left), (width, height), rows)?;
+        Ok(())
+    }
+
+    pub fn draw_circle(
+        &mut self,
+        center: Coord,
+        radius: i16,
+        color: impl DisplayColor,
+        fill: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        if !(0..=0xFF).contains(&radius) {
+            return Err(Error::RadiusOutOfRange);
+        }
+        let (x0, y0) = self.to_hw(center);
+        self.write_register(Register::CircleX0, x0 as u8)?;
+        self.write_register(Register::CircleX1, (x0 >> 8) as u8)?;
+        self.write_register(Register::CircleY0, y0 as u8)?;
+        self.write_register(Register::CircleY1, (y0 >> 8) as u8)?;
+        self.write_register(Register::CircleR, radius as u8)?;
+        self.set_colors(color, None)?;
+        if fill {
+            self.write_register(
+                Register::Dcr,
+                cmds::Dcr::CIRCLE_START as u8 | cmds::Dcr::FILL as u8,
+            )?;
+        } else {
+            self.write_register(Register::Dcr, cmds::Dcr::CIRCLE_START as u8)?;
+        }
+        // Wait for command to finish
+        self.wait_for_draw_complete(Register::Dcr, cmds::Dcr::CIRCLE_START as u8)?;
+        Ok(())
+    }
+
+    /// Draws the arc of a circle running clockwise from `start_angle` to `end_angle`.
+    ///
+    /// Angles are in degrees measured clockwise from 3 o'clock, matching screen coordinates
+    /// where y grows downwards. Quadrants entirely inside the sweep are drawn with the chip's
+    /// native curve command; the partial quadrants at either end are drawn in software.
+    pub fn draw_arc(
+        &mut self,
+        center: Coord,
+        radius: i16,
+        start_angle: u16,
+        end_angle: u16,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.arc(
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            color.into_raw(),
+            false,
+        )
+    }
+
+    /// Draws a filled pie slice running clockwise from `start_angle` to `end_angle`. See
+    /// `draw_arc` for how angles are measured.
+    pub fn fill_arc(
+        &mut self,
+        center: Coord,
+        radius: i16,
+        start_angle: u16,
+        end_angle: u16,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.arc(
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            color.into_raw(),
+            true,
+        )
+    }
+
+    fn arc(
+        &mut self,
+        (cx, cy): Coord,
+        radius: i16,
+        start_angle: u16,
+        end_angle: u16,
+        color: u16,
+        fill: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if radius < 0 {
+            return Err(Error::RadiusOutOfRange);
+        }
+        let start = start_angle % 360;
+        let span = (end_angle % 360 + 360 - start) % 360;
+        if span == 0 && start_angle != end_angle {
+            return self.draw_circle((cx, cy), radius, color, fill);
+        }
+        let in_sweep = |angle: u16| (angle + 360 - start) % 360 <= span;
+        let r = radius as i32;
+
+        // Quadrant q covers angles 90q..=90q+90; its (x, y) signs and the matching
+        // `draw_curve` part, which numbers quadrants clockwise from the bottom-left.
+        const QUADRANTS: [(i32, i32, u8); 4] = [(1, 1, 3), (-1, 1, 0), (-1, -1, 1), (1, -1, 2)];
+        for (q, &(sx, sy, part)) in QUADRANTS.iter().enumerate() {
+            let q_start = q as u16 * 90;
+            let offset = (q_start + 360 - start) % 360;
+            if offset + 90 <= span {
+                self.draw_curve((cx, cy), radius as u16, radius as u16, part, color, fill)?;
+                continue;
+            }
+            if !(0..=90).any(|a| in_sweep((q_start + a) % 360)) {
+                // The sweep doesn't reach into this quadrant at all.
+                continue;
+            }
+
+            for dy in 0..=r {
+                let x_max = isqrt(r * r - dy * dy);
+                let x_inner = if fill || dy == r {
+                    0
+                } else {
+                    isqrt(r * r - (dy + 1) * (dy + 1)) + 1
+                };
+                let x_inner = x_inner.min(x_max);
+                let mut first = None;
+                let mut last = None;
+                for dx in x_inner..=x_max {
+                    if in_sweep(arc_angle(sx * dx, sy * dy)) {
+                        first = first.or(Some(dx));
+                        last = Some(dx);
+                    }
+                }
+                if let (Some(a), Some(b)) = (first, last) {
+                    let y = (cy as i32 + sy * dy) as i16;
+                    self.draw_line(
+                        ((cx as i32 + sx * a) as i16, y),
+                        ((cx as i32 + sx * b) as i16, y),
+                        color,
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Draws line segments connecting consecutive `points`, e.g. for plotting a waveform.
+    ///
+    /// A single point is drawn as a point; an empty slice draws nothing.
+    pub fn draw_polyline(
+        &mut self,
+        points: &[Coord],
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        if let [point] = points {
+            return self.draw_point(*point, color);
+        }
+        for segment in points.windows(2) {
+            self.draw_line(segment[0], segment[1], color)?;
+        }
+        Ok(())
+    }
+
+    /// Draws the closed polygon through `points`, outlined or filled.
+    ///
+    /// The RA8875 has no polygon fill, so filled polygons are decomposed into a fan of
+    /// hardware triangles around the first point. That is only correct for convex polygons;
+    /// concave ones will have parts filled that lie outside the outline.
+    pub fn draw_polygon(
+        &mut self,
+        points: &[Coord],
+        color: impl DisplayColor,
+        fill: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        if points.len() < 3 {
+            return self.draw_polyline(points, color);
+        }
+        if fill {
+            let first = points[0];
+            for pair in points[1..].windows(2) {
+                self.draw_triangle(first, pair[0], pair[1], color, true)?;
+            }
+            Ok(())
+        } else {
+            self.draw_polyline(points, color)?;
+            self.draw_line(points[points.len() - 1], points[0], color)
+        }
+    }
+
+    /// Returns the two outermost vertices of a triangle less than one pixel thick, which is
+    /// drawn as the line between them, or `None` for a proper triangle.
+    fn degenerate_triangle(p0: Coord, p1: Coord, p2: Coord) -> Option<(Coord, Coord)> {
+        let span = |a: Coord, b: Coord| {
+            let dx = (i32::from(b.0) - i32::from(a.0)).abs();
+            let dy = (i32::from(b.1) - i32::from(a.1)).abs();
+            dx.max(dy)
+        };
+        let (start, end) = [(p0, p1), (p1, p2), (p2, p0)]
+            .iter()
+            .copied()
+            .max_by_key(|&(a, b)| span(a, b))?;
+        let ab = (
+            i32::from(p1.0) - i32::from(p0.0),
+            i32::from(p1.1) - i32::from(p0.1),
+        );
+        let ac = (
+            i32::from(p2.0) - i32::from(p0.0),
+            i32::from(p2.1) - i32::from(p0.1),
+        );
+        // Twice the area, over the longest side, is the triangle's height in pixels.
+        let doubled_area = (ab.0 * ac.1 - ab.1 * ac.0).abs();
+        if doubled_area < span(start, end).max(1) {
+            Some((start, end))
+        } else {
+            None
+        }
+    }
+
+    /// Draws a triangle through `p0`, `p1`, and `p2`.
+    ///
+    /// The chip's triangle engine misbehaves on flat triangles: given collinear or nearly
+    /// collinear vertices it can leave stray pixels, or never clear its busy bit. A triangle
+    /// less than a pixel thick is therefore drawn as a line between its two outermost
+    /// vertices, which covers the same pixels. Otherwise the vertices are sent sorted top to
+    /// bottom in hardware coordinates, so the result doesn't depend on the order they were
+    /// passed in.
+    pub fn draw_triangle(
+        &mut self,
+        p0: Coord,
+        p1: Coord,
+        p2: Coord,
+        color: impl DisplayColor,
+        fill: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        if let Some((start, end)) = Self::degenerate_triangle(p0, p1, p2) {
+            return self.draw_line(start, end, color);
+        }
+        let mut vertices = [self.to_hw(p0), self.to_hw(p1), self.to_hw(p2)];
+        vertices.sort_unstable_by_key(|&(x, y)| (y, x));
+        let [(x0, y0), (x1, y1), (x2, y2)] = vertices;
+
+        // Point 0
+        self.write_register(Register::ShapeStartX0, x0 as u8)?;
+        self.write_register(Register::ShapeStartX1, (x0 >> 8) as u8)?;
+        self.write_register(Register::ShapeStartY0, y0 as u8)?;
+        self.write_register(Register::ShapeStartY1, (y0 >> 8) as u8)?;
+
+        // Point 1
+        self.write_register(Register::ShapeEndX0, x1 as u8)?;
+        self.write_register(Register::ShapeEndX1, (x1 >> 8) as u8)?;
+        self.write_register(Register::ShapeEndY0, y1 as u8)?;
+        self.write_register(Register::ShapeEndY1, (y1 >> 8) as u8)?;
+
+        // Point 2
+        self.write_register(Register::TriangleP2X0, x2 as u8)?;
+        self.write_register(Register::TriangleP2X1, (x2 >> 8) as u8)?;
+        self.write_register(Register::TriangleP2Y0, y2 as u8)?;
+        self.write_register(Register::TriangleP2Y1, (y2 >> 8) as u8)?;
+
+        self.set_colors(color, None)?;
+        if fill {
+            self.write_register(
+                Register::Dcr,
+                cmds::Dcr::LINESQUTRI_START as u8 | cmds::Dcr::FILL as u8,
+            )?;
+        } else {
+            self.write_register(Register::Dcr, cmds::Dcr::LINESQUTRI_START as u8)?;
+        }
+        // Wait for command to finish
+        self.wait_for_draw_complete(Register::Dcr, cmds::Dcr::LINESQUTRI_START as u8)?;
+        Ok(())
+    }
+
+    pub fn draw_ellipse(
+        &mut self,
+        center: Coord,
+        long_axis: u16,
+        short_axis: u16,
+        color: impl DisplayColor,
+        fill: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        let (x, y) = self.to_hw(center);
+        let (long_axis, short_axis) = self.to_hw_axes(long_axis, short_axis);
+
+        // Center
+        self.write_register(Register::EllipseCenterX0, x as u8)?;
+        self.write_register(Register::EllipseCenterX1, (x >> 8) as u8)?;
+        self.write_register(Register::EllipseCenterY0, y as u8)?;
+        self.write_register(Register::EllipseCenterY1, (y >> 8) as u8)?;
+
+        // Long Axis
+        self.write_register(Register::EllipseLongA0, long_axis as u8)?;
+        self.write_register(Register::EllipseLongA1, (long_axis >> 8) as u8)?;
+
+        // Short Axis
+        self.write_register(Register::EllipseShortB0, short_axis as u8)?;
+        self.write_register(Register::EllipseShortB1, (short_axis >> 8) as u8)?;
+
+        self.set_colors(color, None)?;
+
+        if fill {
+            self.write_register(
+                Register::DrawEllipseCR,
+                cmds::DrawEllipseCR::DRAWSTART as u8 | cmds::DrawEllipseCR::FILL as u8,
+            )?;
+        } else {
+            self.write_register(
+                Register::DrawEllipseCR,
+                cmds::DrawEllipseCR::DRAWSTART as u8,
+            )?;
+        }
+        self.wait_for_draw_complete(
+            Register::DrawEllipseCR,
+            cmds::DrawEllipseCR::DRAWSTART as u8,
+        )?;
+
+        Ok(())
+    }
+
+    /// Draws a rectangle with quarter-circle corners of `corner_radius`, composed from four
+    /// `draw_curve` corners and straight edges. The radius is limited to half the shorter side.
+    pub fn draw_rounded_rect(
+        &mut self,
+        top_left: Coord,
+        bottom_right: Coord,
+        corner_radius: u16,
+        color: impl DisplayColor,
+        fill: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        let (x0, y0) = (
+            top_left.0.min(bottom_right.0),
+            top_left.1.min(bottom_right.1),
+        );
+        let (x1, y1) = (
+            top_left.0.max(bottom_right.0),
+            top_left.1.max(bottom_right.1),
+        );
+        let r = (corner_radius as i16).min((x1 - x0) / 2).min((y1 - y0) / 2);
+        if r <= 0 {
+            return self.draw_rect((x0, y0), (x1, y1), color, fill);
+        }
+
+        if fill {
+            // A cross of two rectangles covers everything but the corners; they overlap the
+            // curves by a pixel so there are no gaps at the seams.
+            self.draw_rect((x0 + r, y0), (x1 - r, y1), color, true)?;
+            self.draw_rect((x0, y0 + r), (x1, y1 - r), color, true)?;
+        } else {
+            self.draw_hline((x0 + r, y0), x1 - x0 - 2 * r, color)?;
+            self.draw_hline((x0 + r, y1), x1 - x0 - 2 * r, color)?;
+            self.draw_vline((x0, y0 + r), y1 - y0 - 2 * r, color)?;
+            self.draw_vline((x1, y0 + r), y1 - y0 - 2 * r, color)?;
+        }
+
+        let r_axis = r as u16;
+        self.draw_curve((x0 + r, y1 - r), r_axis, r_axis, 0, color, fill)?;
+        self.draw_curve((x0 + r, y0 + r), r_axis, r_axis, 1, color, fill)?;
+        self.draw_curve((x1 - r, y0 + r), r_axis, r_axis, 2, color, fill)?;
+        self.draw_curve((x1 - r, y1 - r), r_axis, r_axis, 3, color, fill)
+    }
+
+    pub fn draw_curve(
+        &mut self,
+        center: Coord,
+        long_axis: u16,
+        short_axis: u16,
+        curve_part: u8,
+        color: impl DisplayColor,
+        fill: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        let (x, y) = self.to_hw(center);
+        let (long_axis, short_axis) = self.to_hw_axes(long_axis, short_axis);
+        // Quadrants are numbered clockwise from the bottom-left, so each quarter turn of
+        // rotation moves a curve one quadrant further round.
+        let curve_part = match self.rotation {
+            Rotation::Rotate0 => curve_part,
+            Rotation::Rotate90 => curve_part.wrapping_add(1),
+            Rotation::Rotate180 => curve_part.wrapping_add(2),
+            Rotation::Rotate270 => curve_part.wrapping_add(3),
+        };
+
+        // Center
+        self.write_register(Register::EllipseCenterX0, x as u8)?;
+        self.write_register(Register::EllipseCenterX1, (x >> 8) as u8)?;
+        self.write_register(Register::EllipseCenterY0, y as u8)?;
+        self.write_register(Register::EllipseCenterY1, (y >> 8) as u8)?;
+
+        // Long Axis
+        self.write_register(Register::EllipseLongA0, long_axis as u8)?;
+        self.write_register(Register::EllipseLongA1, (long_axis >> 8) as u8)?;
+
+        // Short Axis
+        self.write_register(Register::EllipseShortB0, short_axis as u8)?;
+        self.write_register(Register::EllipseShortB1, (short_axis >> 8) as u8)?;
+
+        self.set_colors(color, None)?;
+
+        if fill {
+            self.write_register(
+                Register::DrawEllipseCR,
+                cmds::DrawEllipseCR::DRAWSTART as u8
+                    | cmds::DrawEllipseCR::FILL as u8
+                    | cmds::DrawEllipseCR::ELLIPSE_CURVE_SEL as u8
+                    | (curve_part & cmds::DrawEllipseCR::EllipseCurvePart as u8),
+            )?;
+        } else {
+            self.write_register(
+                Register::DrawEllipseCR,
+                cmds::DrawEllipseCR::DRAWSTART as u8
+                    | cmds::DrawEllipseCR::ELLIPSE_CURVE_SEL as u8
+                    | (curve_part & cmds::DrawEllipseCR::EllipseCurvePart as u8),
+            )?;
         }
-        self.cs.set_high().ok().unwrap();
+        self.wait_for_draw_complete(
+            Register::DrawEllipseCR,
+            cmds::DrawEllipseCR::DRAWSTART as u8,
+        )?;
+
         Ok(())
     }
 
-    /// Sets the cursor position for the current display mode.
-    pub fn set_cursor(&mut self, new_position: Coord) -> Result<(), SpiError<SPI>> {
-        let (x, y) = new_position;
-        match self.mode {
-            Mode::Graphics => {
-                self.write_register(Register::CurH0, x as u8)?;
-                self.write_register(Register::CurH1, (x >> 8) as u8)?;
-                self.write_register(Register::CurV0, y as u8)?;
-                self.write_register(Register::CurV1, (y >> 8) as u8)?;
-                self.gfx_settings.cursor = new_position;
-                Ok(())
-            }
-            Mode::Text => {
-                self.write_register(Register::TextX0, x as u8)?;
-                self.write_register(Register::TextX1, (x >> 8) as u8)?;
-                self.write_register(Register::TextY0, y as u8)?;
-                self.write_register(Register::TextY1, (y >> 8) as u8)?;
-                self.text_settings.cursor = new_position;
-                Ok(())
-            }
-        }
+    /// Enables dual-layer mode and selects how the two layers are composited.
+    ///
+    /// Two full-screen layers only fit in display memory at 8 bits per pixel, so this switches
+    /// the chip to 8bpp. Colors are still passed as RGB565 and are reduced to RGB332 (256
+    /// colors) on the way out, trading color resolution for a second frame to draw into.
+    pub fn set_layer(&mut self, mode: LayerMode) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.set_color_depth(ColorDepth::Bpp8)?;
+
+        let two_layers = cmds::Dpcr::TwoLayers as u8;
+        self.update_dpcr(two_layers, two_layers)?;
+
+        let tmp = self.read_register(Register::Ltpr0)?;
+        block!(self.write_data((tmp & !(cmds::Ltpr0::DisplayModeMask as u8)) | mode as u8))?;
+        Ok(())
     }
 
-    /// Sets the colors for the current display mode. If `bg_color` is `None`, then a transparent
-    /// background will be used.
-    fn set_colors(&mut self, fg_color: u16, bg_color: Option<u16>) -> Result<(), SpiError<SPI>> {
-        match self.mode {
-            Mode::Graphics => {
-                self.write_register(Register::Color0, ((fg_color & 0xf800) >> 11) as u8)?;
-                self.write_register(Register::Color1, ((fg_color & 0x07e0) >> 5) as u8)?;
-                self.write_register(Register::Color2, (fg_color & 0x001f) as u8)?;
-                Ok(())
-            }
-            Mode::Text => {
-                self.write_register(Register::Color0, ((fg_color & 0xf800) >> 11) as u8)?;
-                self.write_register(Register::Color1, ((fg_color & 0x07e0) >> 5) as u8)?;
-                self.write_register(Register::Color2, (fg_color & 0x001f) as u8)?;
+    /// Returns to a single layer at 16 bits per pixel.
+    pub fn single_layer(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.update_dpcr(cmds::Dpcr::TwoLayers as u8, 0)?;
 
-                match bg_color {
-                    Some(color) => {
-                        self.write_register(Register::TextBg0, ((color & 0xf800) >> 11) as u8)?;
-                        self.write_register(Register::TextBg1, ((color & 0x07e0) >> 5) as u8)?;
-                        self.write_register(Register::TextBg2, (color & 0x001f) as u8)?;
-                        // Clear transparency flag
-                        let tmp = self.read_register(Register::FontOptions)?;
-                        block!(self.write_data(tmp & !(1 << 6)))?;
-                    }
-                    None => {
-                        // Set transparency flag
-                        let tmp = self.read_register(Register::FontOptions)?;
-                        block!(self.write_data(tmp | (1 << 6)))?;
-                    }
-                }
+        self.set_color_depth(ColorDepth::Bpp16)
+    }
 
-                self.text_settings.fg_color = fg_color;
-                self.text_settings.bg_color = bg_color;
+    /// Sets how many bits per pixel display memory holds.
+    ///
+    /// Colors are always passed as RGB565; in 8bpp mode they're reduced to RGB332 on the way
+    /// out, so pixel writes send a single byte per pixel. Existing memory contents aren't
+    /// converted, so clear the screen after switching.
+    pub fn set_color_depth(
+        &mut self,
+        depth: ColorDepth,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let bits = match depth {
+            ColorDepth::Bpp8 => cmds::Sysr::BBP_8 as u8,
+            ColorDepth::Bpp16 => cmds::Sysr::BBP_16 as u8,
+        };
+        // Both color depth bits are set for 16bpp, so its pattern doubles as the mask.
+        let tmp = self.read_register(Register::Sysr)?;
+        block!(self.write_data((tmp & !(cmds::Sysr::BBP_16 as u8)) | bits))?;
+        self.color_depth = depth;
+        Ok(())
+    }
 
-                Ok(())
-            }
+    /// The color depth display memory is currently using.
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Sets the order the bytes of each 16bpp pixel are sent in, for boards that expect the
+    /// low byte first. Applies to every pixel write: points, pixel runs, images, and
+    /// `DrawTarget` output.
+    pub fn set_byte_order(&mut self, order: ByteOrder) {
+        self.byte_order = order;
+    }
+
+    /// The byte order pixel writes currently use.
+    pub fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    /// Selects the layer that subsequent drawing and memory writes go to.
+    pub fn select_write_layer(
+        &mut self,
+        layer: Layer,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let tmp = self.read_register(Register::Mwcr1)?;
+        match layer {
+            Layer::Layer1 => block!(self.write_data(tmp & !(cmds::Mwcr1::Layer2 as u8)))?,
+            Layer::Layer2 => block!(self.write_data(tmp | cmds::Mwcr1::Layer2 as u8))?,
         }
+        Ok(())
     }
 
-    fn fill_rect(&mut self) -> Result<(), SpiError<SPI>> {
-        block!(self.write_command(Register::Dcr as u8))?;
-        block!(self.write_data(cmds::Dcr::DRAWSQUARE as u8))?;
-        block!(self.write_data(
-            cmds::Dcr::LINESQUTRI_START as u8 | cmds::Dcr::FILL as u8 | cmds::Dcr::DRAWSQUARE as u8
-        ))?;
+    /// Sets the layer 2 color treated as see-through in `LayerMode::Transparent`.
+    pub fn set_transparent_color(
+        &mut self,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let color = color.into_raw();
+        let (r, g, b) = color_components(self.color_depth, color);
+        self.write_register(Register::Bgtr0, r)?;
+        self.write_register(Register::Bgtr1, g)?;
+        self.write_register(Register::Bgtr2, b)?;
         Ok(())
     }
 
-    /// Draw a single `color` colored point at coordinate `coord`.
-    pub fn draw_point(&mut self, coord: Coord, color: u16) -> Result<(), SpiError<SPI>> {
-        self.set_cursor(coord)?;
-        block!(self.write_command(Register::Mrwc as u8))?;
-        self.cs.set_low().ok().unwrap();
-        self.spi_send(Command::DataWrite as u8)?;
-        self.spi_send((color >> 8) as u8)?;
-        self.spi_send(color as u8)?;
-        self.cs.set_high().ok().unwrap();
+    fn bte_set_source(&mut self, (x, y): Coord) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::Hsbe0, x as u8)?;
+        self.write_register(Register::Hsbe1, (x >> 8) as u8)?;
+        self.write_register(Register::Vsbe0, y as u8)?;
+        self.write_register(Register::Vsbe1, (y >> 8) as u8)?;
         Ok(())
     }
 
-    pub fn draw_line(&mut self, start: Coord, end: Coord, color: u16) -> Result<(), SpiError<SPI>> {
-        let (x0, y0) = start;
-        self.write_register(Register::ShapeStartX0, x0 as u8)?;
-        self.write_register(Register::ShapeStartX1, (x0 >> 8) as u8)?;
-        self.write_register(Register::ShapeStartY0, y0 as u8)?;
-        self.write_register(Register::ShapeStartY1, (y0 >> 8) as u8)?;
-        let (x1, y1) = end;
-        self.write_register(Register::ShapeEndX0, x1 as u8)?;
-        self.write_register(Register::ShapeEndX1, (x1 >> 8) as u8)?;
-        self.write_register(Register::ShapeEndY0, y1 as u8)?;
-        self.write_register(Register::ShapeEndY1, (y1 >> 8) as u8)?;
-        self.set_colors(color, None)?;
-        self.write_register(Register::Dcr, 0x80)?;
-        // Wait for command to finish
-        while (self.read_register(Register::Dcr)? & 0x80) != 0x00 {}
+    fn bte_set_destination(
+        &mut self,
+        (x, y): Coord,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::Hdbe0, x as u8)?;
+        self.write_register(Register::Hdbe1, (x >> 8) as u8)?;
+        self.write_register(Register::Vdbe0, y as u8)?;
+        self.write_register(Register::Vdbe1, (y >> 8) as u8)?;
         Ok(())
     }
 
-    pub fn draw_vline(
+    fn bte_set_size(
         &mut self,
-        start: Coord,
-        height: i16,
-        color: u16,
-    ) -> Result<(), SpiError<SPI>> {
-        self.draw_line(start, (start.0, start.1 + height), color)
+        width: u16,
+        height: u16,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::Bewr0, width as u8)?;
+        self.write_register(Register::Bewr1, (width >> 8) as u8)?;
+        self.write_register(Register::Behr0, height as u8)?;
+        self.write_register(Register::Behr1, (height >> 8) as u8)?;
+        Ok(())
     }
 
-    pub fn draw_hline(
+    /// Starts the BTE with the given raster and BECR1 operations and waits for it to finish.
+    fn bte_run(
         &mut self,
-        start: Coord,
-        width: i16,
-        color: u16,
-    ) -> Result<(), SpiError<SPI>> {
-        self.draw_line(start, (start.0 + width, start.1), color)
+        rop: RasterOp,
+        operation: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::Becr1, rop as u8 | operation)?;
+        self.write_register(Register::Becr0, cmds::Becr0::Enable as u8)?;
+        // Wait for command to finish
+        self.wait_for_draw_complete(Register::Becr0, cmds::Becr0::Enable as u8)?;
+        Ok(())
     }
 
-    pub fn draw_rect(
+    /// Fills a `width` x `height` region with `color` using the Block Transfer Engine.
+    ///
+    /// This is considerably faster than the `draw_rect` path for large regions. Like the other
+    /// drawing methods it takes `top_left`, `width`, and `height` in the rotated frame, and
+    /// maps the region to display memory before starting the engine.
+    pub fn bte_solid_fill(
         &mut self,
         top_left: Coord,
-        bottom_right: Coord,
-        color: u16,
-        fill: bool,
-    ) -> Result<(), SpiError<SPI>> {
+        width: u16,
+        height: u16,
+        color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+        let color = color.into_raw();
         let (x0, y0) = top_left;
-        let (x1, y1) = bottom_right;
-        self.write_register(Register::ShapeStartX0, x0 as u8)?;
-        self.write_register(Register::ShapeStartX1, (x0 >> 8) as u8)?;
-        self.write_register(Register::ShapeStartY0, y0 as u8)?;
-        self.write_register(Register::ShapeStartY1, (y0 >> 8) as u8)?;
-        self.write_register(Register::ShapeEndX0, x1 as u8)?;
-        self.write_register(Register::ShapeEndX1, (x1 >> 8) as u8)?;
-        self.write_register(Register::ShapeEndY0, y1 as u8)?;
-        self.write_register(Register::ShapeEndY1, (y1 >> 8) as u8)?;
+        let (ax, ay) = self.to_hw(top_left);
+        let (bx, by) = self.to_hw((x0 + width as i16 - 1, y0 + height as i16 - 1));
+        self.bte_set_destination((ax.min(bx), ay.min(by)))?;
+        let (width, height) = self.to_hw_axes(width, height);
+        self.bte_set_size(width, height)?;
         self.set_colors(color, None)?;
-        if fill {
-            self.write_register(Register::Dcr, 0xB0)?;
-        } else {
-            self.write_register(Register::Dcr, 0x90)?;
+        self.bte_run(RasterOp::Source, cmds::Becr1::SolidFill as u8)
+    }
+
+    /// Streams `width * height` pixels, row-major in the rotated frame, into the region at
+    /// `top_left`, and returns how many `pixels` held before running out.
+    ///
+    /// The active window is narrowed to the region. Under `Rotate0` and `Rotate270` the write
+    /// direction wraps each row onto the next one, so the whole region goes out in a single
+    /// memory write. The chip has no direction that wraps the right way for `Rotate90` and
+    /// `Rotate180`, so there the cursor is moved to the start of every row. Graphics mode is
+    /// entered for the duration, and the previous window and mode are restored afterwards,
+    /// even when the write fails part way.
+    fn stream_region<I>(
+        &mut self,
+        top_left: Coord,
+        size: (u16, u16),
+        pixels: I,
+    ) -> Result<usize, Error<SpiError<SPI>, PinError<P>>>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        if size.0 == 0 || size.1 == 0 {
+            return Ok(0);
         }
-        // Wait for command to finish
-        while (self.read_register(Register::Dcr)? & 0x80) != 0x00 {}
+        // Reject an off-panel start before anything is changed that would need undoing.
+        let start = self.to_hw(top_left);
+        self.check_bounds(start)?;
+
+        let previous_mode = self.mode;
+        let (saved_top_left, saved_bottom_right) = self.window;
+        let result = self.write_region(top_left, size, pixels);
+        let restored = self
+            .write_active_window(saved_top_left, saved_bottom_right)
+            .and_then(|()| match previous_mode {
+                Mode::Text => self.text_mode(),
+                Mode::Graphics => Ok(()),
+            });
+        let count = result?;
+        restored?;
+        Ok(count)
+    }
+
+    /// The body of `stream_region`, which leaves the mode and window changed for the caller
+    /// to restore.
+    fn write_region<I>(
+        &mut self,
+        top_left: Coord,
+        (width, height): (u16, u16),
+        pixels: I,
+    ) -> Result<usize, Error<SpiError<SPI>, PinError<P>>>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        let total = width as usize * height as usize;
+        self.graphics_mode()?;
+        let (x0, y0) = top_left;
+        let bottom_right = (x0 + width as i16 - 1, y0 + height as i16 - 1);
+        self.set_active_window(top_left, bottom_right)?;
+
+        let depth = self.color_depth;
+        let order = self.byte_order;
+        let mut pixels = pixels.into_iter();
+        let mut count = 0;
+        match self.rotation {
+            Rotation::Rotate0 | Rotation::Rotate270 => {
+                self.set_graphics_cursor(top_left)?;
+                block!(self.write_command(Register::Mrwc as u8))?;
+                self.spi.write_data_iter(
+                    pixels
+                        .take(total)
+                        .inspect(|_| count += 1)
+                        .flat_map(|color| pixel_bytes(depth, order, color)),
+                )?;
+            }
+            Rotation::Rotate90 | Rotation::Rotate180 => {
+                for row in 0..height as i16 {
+                    self.set_graphics_cursor((x0, y0 + row))?;
+                    block!(self.write_command(Register::Mrwc as u8))?;
+                    let before = count;
+                    self.spi.write_data_iter(
+                        pixels
+                            .by_ref()
+                            .take(width as usize)
+                            .inspect(|_| count += 1)
+                            .flat_map(|color| pixel_bytes(depth, order, color)),
+                    )?;
+                    if count - before < width as usize {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    /// Draws an RGB565 image of `size` (width, height) with its top-left corner at `top_left`.
+    ///
+    /// The image streams into a window the size of the image, which is then set back to
+    /// whatever it was before, so the chip wraps each row for us and most rotations need a
+    /// single memory write. Returns `Error::InvalidBufferLength` if `data` doesn't hold
+    /// exactly `width * height` pixels.
+    pub fn draw_image(
+        &mut self,
+        top_left: Coord,
+        size: (u16, u16),
+        data: &[u16],
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if data.len() != size.0 as usize * size.1 as usize {
+            return Err(Error::InvalidBufferLength);
+        }
+        self.stream_region(top_left, size, data.iter().copied())?;
         Ok(())
     }
 
-    pub fn fill_screen(&mut self, color: u16) -> Result<(), SpiError<SPI>> {
-        let (width, height) = self.dims;
-        self.draw_rect((0, 0), (width as i16, height as i16), color, true)
+    /// Draws a `size` (width, height) image whose RGB565 pixels come from an iterator, in
+    /// row-major order, so lazily generated or decoded images needn't be buffered whole.
+    ///
+    /// Like `draw_image`, the image streams into a window the size of the image. Only
+    /// `width * height` pixels are taken from `pixels`. If it runs out before that, the rest
+    /// of the region is left as it was and `Error::InvalidBufferLength` is returned once the
+    /// window has been restored.
+    pub fn draw_image_iter<I>(
+        &mut self,
+        top_left: Coord,
+        size: (u16, u16),
+        pixels: I,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        let count = self.stream_region(top_left, size, pixels)?;
+        if count < size.0 as usize * size.1 as usize {
+            return Err(Error::InvalidBufferLength);
+        }
+        Ok(())
     }
 
-    pub fn draw_circle(
+    /// Repaints just `rect` from `data`, one RGB565 pixel per point in row-major order.
+    ///
+    /// This is `draw_image` addressed by an embedded-graphics rectangle, for partial updates
+    /// of a region that changed, such as a few digits of a clock. Returns
+    /// `Error::InvalidBufferLength` if `data` doesn't cover the rectangle exactly.
+    #[cfg(feature = "embedded-graphics")]
+    pub fn redraw_region(
         &mut self,
-        center: Coord,
-        radius: i16,
-        color: u16,
-        fill: bool,
-    ) -> Result<(), SpiError<SPI>> {
-        let (x0, y0) = center;
-        self.write_register(Register::CircleX0, x0 as u8)?;
-        self.write_register(Register::CircleX1, (x0 >> 8) as u8)?;
-        self.write_register(Register::CircleY0, y0 as u8)?;
-        self.write_register(Register::CircleY1, (y0 >> 8) as u8)?;
-        self.write_register(Register::CircleR, radius as u8)?;
-        self.set_colors(color, None)?;
-        if fill {
+        rect: &primitives::Rectangle,
+        data: &[u16],
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.draw_image(
+            to_coord(rect.top_left),
+            (rect.size.width as u16, rect.size.height as u16),
+            data,
+        )
+    }
+
+    /// Draws a 24-bit `Rgb888` image of `size` (width, height) with its top-left corner at
+    /// `top_left`, reducing each pixel to RGB565 as it streams out.
+    ///
+    /// Otherwise behaves like `draw_image`, including the `Error::InvalidBufferLength` check.
+    #[cfg(feature = "embedded-graphics")]
+    pub fn draw_image_rgb888(
+        &mut self,
+        top_left: Coord,
+        size: (u16, u16),
+        data: &[Rgb888],
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if data.len() != size.0 as usize * size.1 as usize {
+            return Err(Error::InvalidBufferLength);
+        }
+        let pixels = data.iter().map(|&color| Rgb565::from(color).into_storage());
+        self.stream_region(top_left, size, pixels)?;
+        Ok(())
+    }
+
+    /// Draws a `width` pixel wide RGB565 image, leaving pixels equal to `transparent_color`
+    /// untouched so the existing background shows through.
+    ///
+    /// With no rotation set, this uses the Block Transfer Engine's transparent write, which
+    /// drops key-colored pixels in hardware while the image streams in. Because BTE addresses
+    /// display memory directly, a rotated frame falls back to skipping key-colored pixels in
+    /// software and writing the remaining runs individually, which is slower. Either way the
+    /// image is drawn in graphics mode, and text mode is restored afterwards if it was active.
+    ///
+    /// Returns `Error::InvalidBufferLength` if `data` isn't a whole number of rows.
+    pub fn draw_image_transparent(
+        &mut self,
+        top_left: Coord,
+        width: u16,
+        data: &[u16],
+        transparent_color: impl DisplayColor,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let key = transparent_color.into_raw();
+        let width = width as usize;
+        if width == 0 || data.len() % width != 0 {
+            return Err(Error::InvalidBufferLength);
+        }
+        let height = data.len() / width;
+        if height == 0 {
+            return Ok(());
+        }
+
+        let previous_mode = self.mode;
+        self.graphics_mode()?;
+        if self.rotation == Rotation::Rotate0 {
+            self.bte_set_destination(top_left)?;
+            self.bte_set_size(width as u16, height as u16)?;
+            // The transparent write takes its key from the foreground color.
+            self.set_colors(key, None)?;
             self.write_register(
-                Register::Dcr,
-                cmds::Dcr::CIRCLE_START as u8 | cmds::Dcr::FILL as u8,
+                Register::Becr1,
+                RasterOp::Source as u8 | cmds::Becr1::TransparentWrite as u8,
             )?;
+            self.write_register(Register::Becr0, cmds::Becr0::Enable as u8)?;
+            self.push_pixel_slice(data)?;
+            self.wait_for_draw_complete(Register::Becr0, cmds::Becr0::Enable as u8)?;
+        } else {
+            let (x0, y0) = top_left;
+            for (dy, row) in data.chunks(width).enumerate() {
+                let mut start = 0;
+                while start < row.len() {
+                    if row[start] == key {
+                        start += 1;
+                        continue;
+                    }
+                    let end = row[start..]
+                        .iter()
+                        .position(|&color| color == key)
+                        .map_or(row.len(), |len| start + len);
+                    self.set_graphics_cursor((x0 + start as i16, y0 + dy as i16))?;
+                    self.push_pixel_slice(&row[start..end])?;
+                    start = end;
+                }
+            }
+        }
+        if let Mode::Text = previous_mode {
+            self.text_mode()?;
+        }
+        Ok(())
+    }
+
+    /// Draws a 1-bit monochrome bitmap of `size` (width, height), such as an icon or a glyph
+    /// from an atlas, expanding set bits to `fg` and clear bits to `bg` as it streams out.
+    ///
+    /// Rows are packed most significant bit first and padded to a whole byte, the layout of
+    /// embedded-graphics' `BinaryColor` images, so `data` holds `ceil(width / 8) * height`
+    /// bytes or `Error::InvalidBufferLength` is returned. With a background the bitmap streams
+    /// like `draw_image_iter`. With `bg` of `None` clear bits are left untouched, and each
+    /// horizontal run of set bits is written separately. Either way the bitmap is drawn in
+    /// graphics mode, and text mode is restored afterwards if it was active.
+    pub fn draw_bitmap_1bpp<C: DisplayColor>(
+        &mut self,
+        top_left: Coord,
+        (width, height): (u16, u16),
+        data: &[u8],
+        fg: C,
+        bg: Option<C>,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (width, height) = (width as usize, height as usize);
+        let stride = (width + 7) / 8;
+        if data.len() != stride * height {
+            return Err(Error::InvalidBufferLength);
+        }
+        let (fg, bg) = (fg.into_raw(), bg.map(C::into_raw));
+        let bit = |x: usize, y: usize| data[y * stride + x / 8] & (0x80 >> (x % 8)) != 0;
+
+        if let Some(bg) = bg {
+            let pixels = (0..height)
+                .flat_map(move |y| (0..width).map(move |x| if bit(x, y) { fg } else { bg }));
+            return self.draw_image_iter(top_left, (width as u16, height as u16), pixels);
+        }
+
+        let previous_mode = self.mode;
+        self.graphics_mode()?;
+        let (x0, y0) = top_left;
+        for y in 0..height {
+            let mut x = 0;
+            while x < width {
+                if !bit(x, y) {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while x < width && bit(x, y) {
+                    x += 1;
+                }
+                self.set_graphics_cursor((x0 + start as i16, y0 + y as i16))?;
+                self.push_pixels((x - start) as u32, fg)?;
+            }
+        }
+        if let Mode::Text = previous_mode {
+            self.text_mode()?;
+        }
+        Ok(())
+    }
+
+    /// Uploads a fill pattern, row by row, into the chip's pattern RAM for `fill_pattern`.
+    ///
+    /// Returns `Error::InvalidBufferLength` unless `data` holds exactly 64 or 256 pixels to
+    /// match `size`.
+    pub fn set_pattern(
+        &mut self,
+        data: &[u16],
+        size: PatternSize,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (len, ptno) = match size {
+            PatternSize::Size8x8 => (64, 0x00),
+            PatternSize::Size16x16 => (256, cmds::Ptno::Size16x16 as u8),
+        };
+        if data.len() != len {
+            return Err(Error::InvalidBufferLength);
+        }
+        self.write_register(Register::Ptno, ptno)?;
+
+        // Point memory writes at pattern RAM for the upload, then back at display memory.
+        // Both destination bits are set for pattern RAM, so its pattern doubles as the mask.
+        let mwcr1 = self.read_register(Register::Mwcr1)? & !(cmds::Mwcr1::DestPattern as u8);
+        self.write_register(Register::Mwcr1, mwcr1 | cmds::Mwcr1::DestPattern as u8)?;
+        self.push_pixel_slice(data)?;
+        self.write_register(Register::Mwcr1, mwcr1)
+    }
+
+    /// Tiles the pattern loaded with `set_pattern` across a `width` x `height` region using
+    /// the Block Transfer Engine.
+    pub fn fill_pattern(
+        &mut self,
+        top_left: Coord,
+        width: u16,
+        height: u16,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.bte_set_source((0, 0))?;
+        self.bte_set_destination(top_left)?;
+        self.bte_set_size(width, height)?;
+        self.bte_run(RasterOp::Source, cmds::Becr1::PatternFill as u8)
+    }
+
+    /// Copies a `width` x `height` region of display memory from `src_top_left` to
+    /// `dst_top_left` using the Block Transfer Engine.
+    ///
+    /// Overlapping regions are handled: when the destination lies after the source in memory
+    /// order the copy runs backwards from the bottom-right corner so source pixels are read
+    /// before they're overwritten.
+    pub fn bte_copy(
+        &mut self,
+        src_top_left: Coord,
+        dst_top_left: Coord,
+        width: u16,
+        height: u16,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.bte_copy_rop(src_top_left, dst_top_left, width, height, RasterOp::Source)
+    }
+
+    /// Like `bte_copy`, but combines each source pixel with the destination pixel it lands
+    /// on through `rop`, e.g. `RasterOp::Xor` for a selection box that a
+    /// second copy erases again.
+    pub fn bte_copy_rop(
+        &mut self,
+        (sx, sy): Coord,
+        (dx, dy): Coord,
+        width: u16,
+        height: u16,
+        rop: RasterOp,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+        let move_negative = dy > sy || (dy == sy && dx > sx);
+        if move_negative {
+            // Negative moves are addressed from the bottom-right corner of each block.
+            let (w, h) = (width as i16 - 1, height as i16 - 1);
+            self.bte_set_source((sx + w, sy + h))?;
+            self.bte_set_destination((dx + w, dy + h))?;
         } else {
-            self.write_register(Register::Dcr, cmds::Dcr::CIRCLE_START as u8)?;
+            self.bte_set_source((sx, sy))?;
+            self.bte_set_destination((dx, dy))?;
+        }
+        self.bte_set_size(width, height)?;
+        if move_negative {
+            self.bte_run(rop, cmds::Becr1::MoveNegative as u8)
+        } else {
+            self.bte_run(rop, cmds::Becr1::MovePositive as u8)
         }
-        // Wait for command to finish
-        while (self.read_register(Register::Dcr)? & cmds::Dcr::CIRCLE_START as u8) != 0x00 {}
-        Ok(())
     }
 
-    pub fn draw_triangle(
+    /// Inverts the colors currently on screen, e.g. for a dark-mode flash.
+    ///
+    /// The RA8875 has no display-level inversion, so this inverts display memory in place
+    /// with a BTE move through a NOT raster operation. It is a one-shot operation rather than
+    /// a mode: anything drawn afterwards appears normally, and calling it again inverts the
+    /// screen as it is then.
+    pub fn invert_screen(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (width, height) = self.dims;
+        self.bte_set_source((0, 0))?;
+        self.bte_set_destination((0, 0))?;
+        self.bte_set_size(width as u16, height as u16)?;
+        self.bte_run(RasterOp::NotDestination, cmds::Becr1::MovePositive as u8)
+    }
+
+    /// Turns the panel output on or off; the same as `display_on`, named to match the other
+    /// display-level controls.
+    pub fn set_display_enable(
         &mut self,
-        (x0, y0): Coord,
-        (x1, y1): Coord,
-        (x2, y2): Coord,
-        color: u16,
-        fill: bool,
-    ) -> Result<(), SpiError<SPI>> {
-        // Point 0
-        self.write_register(Register::ShapeStartX0, x0 as u8)?;
-        self.write_register(Register::ShapeStartX1, (x0 >> 8) as u8)?;
-        self.write_register(Register::ShapeStartY0, y0 as u8)?;
-        self.write_register(Register::ShapeStartY1, (y0 >> 8) as u8)?;
+        enable: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.display_on(enable)
+    }
 
-        // Point 1
-        self.write_register(Register::ShapeEndX0, x1 as u8)?;
-        self.write_register(Register::ShapeEndX1, (x1 >> 8) as u8)?;
-        self.write_register(Register::ShapeEndY0, y1 as u8)?;
-        self.write_register(Register::ShapeEndY1, (y1 >> 8) as u8)?;
+    /// Mirrors the panel scan left to right, for panels mounted the other way round.
+    pub fn set_horizontal_flip(
+        &mut self,
+        flip: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let bit = cmds::Dpcr::HDir as u8;
+        self.update_dpcr(bit, if flip { bit } else { 0 })
+    }
 
-        // Point 2
-        self.write_register(Register::TriangleP2X0, x2 as u8)?;
-        self.write_register(Register::TriangleP2X1, (x2 >> 8) as u8)?;
-        self.write_register(Register::TriangleP2Y0, y2 as u8)?;
-        self.write_register(Register::TriangleP2Y1, (y2 >> 8) as u8)?;
+    /// Mirrors the panel scan top to bottom, for panels mounted the other way round.
+    pub fn set_vertical_flip(
+        &mut self,
+        flip: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let bit = cmds::Dpcr::VDir as u8;
+        self.update_dpcr(bit, if flip { bit } else { 0 })
+    }
 
-        self.set_colors(color, None)?;
-        if fill {
-            self.write_register(
-                Register::Dcr,
-                cmds::Dcr::LINESQUTRI_START as u8 | cmds::Dcr::FILL as u8,
-            )?;
-        } else {
-            self.write_register(Register::Dcr, cmds::Dcr::LINESQUTRI_START as u8)?;
+    /// Sets both scan directions at once, e.g. to correct a panel that's mirrored left to
+    /// right but not top to bottom. The flips are independent of `set_rotation` and affect
+    /// the whole panel, including what's already drawn.
+    pub fn set_flip(
+        &mut self,
+        horizontal: bool,
+        vertical: bool,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let mut bits = 0;
+        if horizontal {
+            bits |= cmds::Dpcr::HDir as u8;
         }
-        // Wait for command to finish
-        while (self.read_register(Register::Dcr)? & cmds::Dcr::LINESQUTRI_START as u8) != 0x00 {}
-        Ok(())
+        if vertical {
+            bits |= cmds::Dpcr::VDir as u8;
+        }
+        self.update_dpcr(cmds::Dpcr::HDir as u8 | cmds::Dpcr::VDir as u8, bits)
     }
 
-    pub fn draw_ellipse(
+    /// Replaces the DPCR bits selected by `mask` with those of `value`, keeping the rest.
+    fn update_dpcr(
         &mut self,
-        (x, y): Coord,
-        long_axis: u16,
-        short_axis: u16,
-        color: u16,
-        fill: bool,
-    ) -> Result<(), SpiError<SPI>> {
-        // Center
-        self.write_register(Register::EllipseCenterX0, x as u8)?;
-        self.write_register(Register::EllipseCenterX1, (x >> 8) as u8)?;
-        self.write_register(Register::EllipseCenterY0, y as u8)?;
-        self.write_register(Register::EllipseCenterY1, (y >> 8) as u8)?;
-
-        // Long Axis
-        self.write_register(Register::EllipseLongA0, long_axis as u8)?;
-        self.write_register(Register::EllipseLongA1, (long_axis >> 8) as u8)?;
+        mask: u8,
+        value: u8,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let tmp = self.read_register(Register::Dpcr)?;
+        block!(self.write_data((tmp & !mask) | (value & mask)))?;
+        Ok(())
+    }
 
-        // Short Axis
-        self.write_register(Register::EllipseShortB0, short_axis as u8)?;
-        self.write_register(Register::EllipseShortB1, (short_axis >> 8) as u8)?;
+    /// Confines drawing to the rectangle between `top_left` and `bottom_right`, inclusive.
+    ///
+    /// Fills, text wrapping, and memory-write auto-increment all stay inside the window.
+    /// Cursor coordinates remain absolute screen coordinates rather than window-relative,
+    /// so move the cursor inside the window before drawing into it.
+    pub fn set_active_window(
+        &mut self,
+        top_left: Coord,
+        bottom_right: Coord,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (ax, ay) = self.to_hw(top_left);
+        let (bx, by) = self.to_hw(bottom_right);
+        self.write_active_window((ax.min(bx), ay.min(by)), (ax.max(bx), ay.max(by)))
+    }
 
-        self.set_colors(color, None)?;
+    /// Restores the active window to the full screen.
+    pub fn reset_active_window(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let (width, height) = (self.dims.0 as i16, self.dims.1 as i16);
+        self.write_active_window((0, 0), (width - 1, height - 1))
+    }
 
-        if fill {
-            self.write_register(
-                Register::DrawEllipseCR,
-                cmds::DrawEllipseCR::DRAWSTART as u8 | cmds::DrawEllipseCR::FILL as u8,
-            )?;
-        } else {
-            self.write_register(
-                Register::DrawEllipseCR,
-                cmds::DrawEllipseCR::DRAWSTART as u8,
-            )?;
+    /// Sets the active window in hardware coordinates. While a text window is in effect the
+    /// registers are left alone and the new window is applied on leaving text mode.
+    fn write_active_window(
+        &mut self,
+        top_left: Coord,
+        bottom_right: Coord,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.window = (top_left, bottom_right);
+        if let (Mode::Text, Some(_)) = (self.mode, self.text_window) {
+            return Ok(());
         }
-        while (self.read_register(Register::DrawEllipseCR)? & cmds::DrawEllipseCR::DRAWSTART as u8)
-            != 0x00
-        {}
-
-        Ok(())
+        self.write_window_registers(top_left, bottom_right)
     }
 
-    pub fn draw_curve(
+    /// Writes the active window registers in hardware coordinates.
+    fn write_window_registers(
         &mut self,
-        (x, y): Coord,
-        long_axis: u16,
-        short_axis: u16,
-        curve_part: u8,
-        color: u16,
-        fill: bool,
-    ) -> Result<(), SpiError<SPI>> {
-        // Center
-        self.write_register(Register::EllipseCenterX0, x as u8)?;
-        self.write_register(Register::EllipseCenterX1, (x >> 8) as u8)?;
-        self.write_register(Register::EllipseCenterY0, y as u8)?;
-        self.write_register(Register::EllipseCenterY1, (y >> 8) as u8)?;
-
-        // Long Axis
-        self.write_register(Register::EllipseLongA0, long_axis as u8)?;
-        self.write_register(Register::EllipseLongA1, (long_axis >> 8) as u8)?;
+        (x0, y0): Coord,
+        (x1, y1): Coord,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::Hsaw0, x0 as u8)?;
+        self.write_register(Register::Hsaw1, (x0 >> 8) as u8)?;
+        self.write_register(Register::Heaw0, x1 as u8)?;
+        self.write_register(Register::Heaw1, (x1 >> 8) as u8)?;
 
-        // Short Axis
-        self.write_register(Register::EllipseShortB0, short_axis as u8)?;
-        self.write_register(Register::EllipseShortB1, (short_axis >> 8) as u8)?;
+        self.write_register(Register::Vsaw0, y0 as u8)?;
+        self.write_register(Register::Vsaw1, (y0 >> 8) as u8)?;
+        self.write_register(Register::Veaw0, y1 as u8)?;
+        self.write_register(Register::Veaw1, (y1 >> 8) as u8)
+    }
 
-        self.set_colors(color, None)?;
+    /// Configures the hardware scroll window and which layers it moves.
+    ///
+    /// Content scrolled out of one edge of the window wraps around to the opposite edge, so
+    /// a text console only needs to clear and redraw the newly exposed line.
+    pub fn set_scroll_window(
+        &mut self,
+        (x0, y0): Coord,
+        (x1, y1): Coord,
+        mode: ScrollMode,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::Hssw0, x0 as u8)?;
+        self.write_register(Register::Hssw1, (x0 >> 8) as u8)?;
+        self.write_register(Register::Vssw0, y0 as u8)?;
+        self.write_register(Register::Vssw1, (y0 >> 8) as u8)?;
+        self.write_register(Register::Hesw0, x1 as u8)?;
+        self.write_register(Register::Hesw1, (x1 >> 8) as u8)?;
+        self.write_register(Register::Vesw0, y1 as u8)?;
+        self.write_register(Register::Vesw1, (y1 >> 8) as u8)?;
 
-        if fill {
-            self.write_register(
-                Register::DrawEllipseCR,
-                cmds::DrawEllipseCR::DRAWSTART as u8
-                    | cmds::DrawEllipseCR::FILL as u8
-                    | cmds::DrawEllipseCR::ELLIPSE_CURVE_SEL as u8
-                    | (curve_part & cmds::DrawEllipseCR::EllipseCurvePart as u8),
-            )?;
-        } else {
-            self.write_register(
-                Register::DrawEllipseCR,
-                cmds::DrawEllipseCR::DRAWSTART as u8
-                    | cmds::DrawEllipseCR::ELLIPSE_CURVE_SEL as u8
-                    | (curve_part & cmds::DrawEllipseCR::EllipseCurvePart as u8),
-            )?;
-        }
-        while (self.read_register(Register::DrawEllipseCR)? & cmds::DrawEllipseCR::DRAWSTART as u8)
-            != 0x00
-        {}
+        let tmp = self.read_register(Register::Ltpr0)?;
+        block!(self.write_data((tmp & !(cmds::Ltpr0::ScrollModeMask as u8)) | mode as u8))?;
+        Ok(())
+    }
 
+    /// Scrolls the contents of the scroll window by the given offsets. Offsets are absolute,
+    /// not relative to the previous scroll position; pass `0` for `x_offset` to scroll only
+    /// vertically.
+    pub fn scroll_to(
+        &mut self,
+        x_offset: u16,
+        y_offset: u16,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::Hofs0, x_offset as u8)?;
+        self.write_register(Register::Hofs1, (x_offset >> 8) as u8)?;
+        self.write_register(Register::Vofs0, y_offset as u8)?;
+        self.write_register(Register::Vofs1, (y_offset >> 8) as u8)?;
         Ok(())
     }
 
     /// Enable the touch panel, establish auto mode, and enable touch interrupts.
-    pub fn enable_touch(&mut self) -> Result<(), SpiError<SPI>> {
+    pub fn enable_touch(&mut self) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
         self.write_register(
             Register::Tpcr0,
             cmds::Tpcr0::ENABLE as u8
@@ -946,96 +4076,430 @@ where
         Ok(())
     }
 
+    /// Enables the touch controller in manual mode, where each axis is converted on demand
+    /// with `sample_x` and `sample_y`. This allows oversampling and discarding noisy
+    /// readings, which auto mode doesn't.
+    pub fn enable_touch_manual(
+        &mut self,
+        config: TouchConfig,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(
+            Register::Tpcr0,
+            cmds::Tpcr0::ENABLE as u8 | config.sample_time as u8 | config.adc_clock as u8,
+        )?;
+        let mut tpcr1 = cmds::Tprc1::MANUAL as u8;
+        if config.external_vref {
+            tpcr1 |= cmds::Tprc1::VREFEXT as u8;
+        }
+        if config.debounce {
+            tpcr1 |= cmds::Tprc1::DEBOUNCE as u8;
+        }
+        self.touch_manual = tpcr1;
+        self.write_register(Register::Tpcr1, tpcr1 | cmds::Tprc1::WAIT as u8)
+    }
+
+    /// Latches and converts the X axis in manual mode, returning the raw 10-bit reading.
+    pub fn sample_x<D: DelayMs<u8>>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<u16, Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(
+            Register::Tpcr1,
+            self.touch_manual | cmds::Tprc1::LATCHX as u8,
+        )?;
+        delay.delay_ms(1);
+        let high = self.read_register(Register::Tpxh)? as u16;
+        let low = self.read_register(Register::Tpxyl)? as u16;
+        self.write_register(Register::Tpcr1, self.touch_manual | cmds::Tprc1::WAIT as u8)?;
+        Ok((high << 2) | (low & 0x03))
+    }
+
+    /// Latches and converts the Y axis in manual mode, returning the raw 10-bit reading.
+    pub fn sample_y<D: DelayMs<u8>>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<u16, Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(
+            Register::Tpcr1,
+            self.touch_manual | cmds::Tprc1::LATCHY as u8,
+        )?;
+        delay.delay_ms(1);
+        let high = self.read_register(Register::Tpyh)? as u16;
+        let low = self.read_register(Register::Tpxyl)? as u16;
+        self.write_register(Register::Tpcr1, self.touch_manual | cmds::Tprc1::WAIT as u8)?;
+        Ok((high << 2) | ((low >> 2) & 0x03))
+    }
+
     /// Check if touch event interrupt occurred
-    pub fn touched(&mut self) -> Result<bool, SpiError<SPI>> {
-        Ok(self.read_register(Register::Intc2)? & cmds::Intc2::TP as u8 != 0x00)
+    pub fn touched(&mut self) -> Result<bool, Error<SpiError<SPI>, PinError<P>>> {
+        Ok(self.interrupt_status()?.touch)
+    }
+
+    /// Reads which interrupt sources are pending, for dispatching a shared interrupt line.
+    pub fn interrupt_status(
+        &mut self,
+    ) -> Result<InterruptFlags, Error<SpiError<SPI>, PinError<P>>> {
+        let status = self.read_register(Register::Intc2)?;
+        Ok(InterruptFlags {
+            touch: status & cmds::Intc2::TP as u8 != 0x00,
+            key: status & cmds::Intc2::KEY as u8 != 0x00,
+            dma: status & cmds::Intc2::DMA as u8 != 0x00,
+            bte: status & cmds::Intc2::BTE as u8 != 0x00,
+        })
+    }
+
+    /// Clears a pending interrupt flag.
+    pub fn clear_interrupt(
+        &mut self,
+        flag: Interrupt,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        self.write_register(Register::Intc2, flag as u8)
     }
 
-    pub fn get_touch(&mut self) -> Result<Coord, SpiError<SPI>> {
-        // unimplemented!()
+    /// Returns the raw touch reading, rotated to match the drawing frame, or `None` if the
+    /// panel isn't being touched.
+    ///
+    /// The touch interrupt flag is checked first so no stale coordinates are reported
+    /// between touches, and cleared once the reading has been taken.
+    pub fn get_touch(&mut self) -> Result<Option<Coord>, Error<SpiError<SPI>, PinError<P>>> {
+        if !self.touched()? {
+            return Ok(None);
+        }
         let tx_high = self.read_register(Register::Tpxh)? as u16;
         let ty_high = self.read_register(Register::Tpyh)? as u16;
         let t_xy_lower_bits = self.read_register(Register::Tpxyl)? as u16;
         let tx = (tx_high << 2) | (t_xy_lower_bits & 0x03);
         let ty = (ty_high << 2) | ((t_xy_lower_bits >> 2) & 0x03);
 
-        // Clear the touch interrupt
-        self.write_register(Register::Intc2, cmds::Intc2::TP as u8)?;
+        self.clear_interrupt(Interrupt::Touch)?;
+
+        Ok(Some(self.rotate_touch(tx, ty)))
+    }
+
+    /// Maps a raw touch reading into the rotated drawing frame.
+    fn rotate_touch(&self, tx: u16, ty: u16) -> Coord {
+        // Readings span the full 10-bit ADC range regardless of panel size.
+        let (tx, ty) = (tx as i16, ty as i16);
+        let max = touch::TOUCH_ADC_MAX as i16;
+        match self.rotation {
+            Rotation::Rotate0 => (tx, ty),
+            Rotation::Rotate90 => (ty, max - tx),
+            Rotation::Rotate180 => (max - tx, max - ty),
+            Rotation::Rotate270 => (max - ty, tx),
+        }
+    }
+
+    /// Samples the touch panel back to back in manual mode, filling `buf` with raw readings
+    /// for as long as the panel stays touched. Returns how many samples were taken.
+    ///
+    /// The RA8875 keeps no touch FIFO, so auto mode only ever holds the latest reading. Call
+    /// this from the touch interrupt to collect the rest of a stroke densely instead. Readings
+    /// are rotated like `get_touch`'s, and `enable_touch_manual` must have been called first.
+    ///
+    /// Each sample converts both axes with a 1 ms settling delay apiece, so expect roughly
+    /// 500 samples per second. The conversion itself, the `TouchConfig` sample time in system
+    /// clocks at the ADC clock's divider, is well under that delay at typical clock rates;
+    /// lowering it mainly reduces noise immunity rather than raising the rate.
+    pub fn poll_touch_stream<D: DelayMs<u8>>(
+        &mut self,
+        buf: &mut [Coord],
+        delay: &mut D,
+    ) -> Result<usize, Error<SpiError<SPI>, PinError<P>>> {
+        let mut count = 0;
+        while count < buf.len() {
+            let tx = self.sample_x(delay)?;
+            let ty = self.sample_y(delay)?;
+            if self.read_register(Register::Tpxyl)? & cmds::Tpxyl::TouchDetected as u8 == 0 {
+                break;
+            }
+            buf[count] = self.rotate_touch(tx, ty);
+            count += 1;
+        }
+        self.clear_interrupt(Interrupt::Touch)?;
+        Ok(count)
+    }
+
+    /// Sets the calibration used by `get_touch_point`.
+    #[cfg(feature = "embedded-graphics")]
+    pub fn set_touch_calibration(&mut self, calibration: TouchCalibration) {
+        self.touch_calibration = Some(calibration);
+    }
+
+    /// Reads the touch panel and returns the touched pixel, clamped to the display bounds, or
+    /// `None` if the panel isn't being touched.
+    ///
+    /// This is the one-call touch API: it checks the touch interrupt, reads the raw X/Y
+    /// conversion, maps it into the current rotation, and applies the calibration, yielding a
+    /// point in the same frame `DrawTarget` draws in. Calibrate in that frame too, i.e. after
+    /// calling `set_rotation`.
+    ///
+    /// Without a calibration set via `set_touch_calibration`, the full ADC range is mapped
+    /// linearly onto the screen. `get_touch` remains available for raw readings.
+    #[cfg(feature = "embedded-graphics")]
+    pub fn get_touch_point(&mut self) -> Result<Option<Point>, Error<SpiError<SPI>, PinError<P>>> {
+        let (tx, ty) = match self.get_touch()? {
+            Some(raw) => raw,
+            None => return Ok(None),
+        };
+        let size = self.size();
+        let calibration = match self.touch_calibration {
+            Some(calibration) => calibration,
+            None => TouchCalibration::from_bounds(
+                Point::zero(),
+                Point::new(touch::TOUCH_ADC_MAX, touch::TOUCH_ADC_MAX),
+                size,
+            )
+            .unwrap(),
+        };
+        let point = calibration.apply(Point::new(tx as i32, ty as i32));
+        Ok(Some(Point::new(
+            point.x.max(0).min(size.width as i32 - 1),
+            point.y.max(0).min(size.height as i32 - 1),
+        )))
+    }
+
+    /// Appends the current calibrated touch point to `stroke`, stamped with `now`, for
+    /// recording gestures or signatures. Returns how many points were captured: 1, or 0 if
+    /// the panel isn't being touched.
+    ///
+    /// Call this from the touch interrupt or a polling loop; the interrupt is cleared after
+    /// every reading. Use `TouchStroke::is_idle` with the same clock to decide when the
+    /// stroke has ended.
+    #[cfg(feature = "embedded-graphics")]
+    pub fn record_touch<const N: usize>(
+        &mut self,
+        stroke: &mut TouchStroke<N>,
+        now: u32,
+    ) -> Result<usize, Error<SpiError<SPI>, PinError<P>>> {
+        match self.get_touch_point()? {
+            Some(point) => {
+                stroke.push(TouchSample {
+                    point,
+                    timestamp: now,
+                });
+                Ok(1)
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// Enables the key-scan controller and its KEY interrupt.
+    pub fn enable_keyscan(
+        &mut self,
+        config: KeyScanConfig,
+    ) -> Result<(), Error<SpiError<SPI>, PinError<P>>> {
+        let mut kscr1 =
+            cmds::Kscr1::Enable as u8 | config.sample_time as u8 | (config.scan_frequency & 0x07);
+        if config.long_press {
+            kscr1 |= cmds::Kscr1::LongKey as u8;
+        }
+        self.write_register(Register::Kscr1, kscr1)?;
+
+        let mut kscr2 = (config.long_press_time & 0x03) << 2;
+        if config.wake_on_key {
+            kscr2 |= cmds::Kscr2::WakeUp as u8;
+        }
+        self.write_register(Register::Kscr2, kscr2)?;
+
+        let tmp = self.read_register(Register::Intc1)?;
+        self.write_register(Register::Intc1, tmp | cmds::Intc1::KEY as u8)
+    }
 
-        Ok((tx as i16, ty as i16))
+    /// Returns the scanned key if the KEY interrupt has fired, clearing the interrupt.
+    ///
+    /// When several keys are pressed at once only the first is reported.
+    pub fn read_key(&mut self) -> Result<Option<KeyEvent>, Error<SpiError<SPI>, PinError<P>>> {
+        if !self.interrupt_status()?.key {
+            return Ok(None);
+        }
+        let count = self.read_register(Register::Kscr2)? & cmds::Kscr2::KeyCountMask as u8;
+        let event = if count > 0 {
+            Some(KeyEvent::from_code(self.read_register(Register::Ksdr0)?))
+        } else {
+            None
+        };
+        self.clear_interrupt(Interrupt::Key)?;
+        Ok(event)
     }
 }
 
+/// Panel timing parameters, taken from the panel's datasheet.
+///
+/// Horizontal values are in pixel clocks and vertical values in lines, before the
+/// register encoding `init_with_timing` applies.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Timing {
-    pixclk: u8,
-    hsync_start: u8,
-    hsync_pw: u8,
-    hsync_finetune: u8,
-    hsync_nondisp: u8,
-    vsync_pw: u8,
-    vsync_nondisp: u16,
-    vsync_start: u16,
+    /// Raw PCSR value: bit 7 latches pixel data on the falling edge, and bits 1:0 divide the
+    /// system clock by 1, 2, 4, or 8 to produce the pixel clock.
+    pub pixclk: u8,
+    /// Horizontal sync start position.
+    pub hsync_start: u8,
+    /// Horizontal sync pulse width.
+    pub hsync_pw: u8,
+    /// Horizontal non-display period fine tuning.
+    pub hsync_finetune: u8,
+    /// Horizontal non-display period.
+    pub hsync_nondisp: u8,
+    /// Vertical sync pulse width.
+    pub vsync_pw: u8,
+    /// Vertical non-display period.
+    pub vsync_nondisp: u16,
+    /// Vertical sync start position.
+    pub vsync_start: u16,
+}
+
+/// Timing values after the register encoding, each one less than the raw value.
+struct TimingRegisters {
+    hndr: u8,
+    hstr: u8,
+    hpwr: u8,
+    vndr: u16,
+    vstr: u16,
+    vpwr: u8,
+}
+
+impl Timing {
+    /// Encodes the timing for the HNDR, HSTR, HPWR, VNDR, VSTR, and VPWR registers, or
+    /// returns `None` if a value is too small to encode.
+    fn registers(&self) -> Option<TimingRegisters> {
+        let hndr = self
+            .hsync_nondisp
+            .checked_sub(self.hsync_finetune)?
+            .checked_sub(2)?
+            / 8;
+        Some(TimingRegisters {
+            hndr,
+            hstr: (self.hsync_start / 8).checked_sub(1)?,
+            hpwr: (self.hsync_pw / 8).checked_sub(1)?,
+            vndr: self.vsync_nondisp.checked_sub(1)?,
+            vstr: self.vsync_start.checked_sub(1)?,
+            vpwr: self.vsync_pw.checked_sub(1)?,
+        })
+    }
+
+    /// Looks up the panel timing for a supported resolution.
+    fn for_dimensions(dims: (u32, u32)) -> Option<Timing> {
+        match dims {
+            (320, 240) => Some(Timing {
+                pixclk: cmds::Pcsr::Pdatl as u8 | cmds::Pcsr::Clk_8 as u8,
+                hsync_nondisp: 10,
+                hsync_start: 8,
+                hsync_pw: 48,
+                hsync_finetune: 0,
+                vsync_nondisp: 3,
+                vsync_start: 8,
+                vsync_pw: 10,
+            }),
+            (480, 272) => Some(Timing {
+                pixclk: cmds::Pcsr::Pdatl as u8 | cmds::Pcsr::Clk_4 as u8,
+                hsync_nondisp: 10,
+                hsync_start: 8,
+                hsync_pw: 48,
+                hsync_finetune: 0,
+                vsync_nondisp: 3,
+                vsync_start: 8,
+                vsync_pw: 10,
+            }),
+            (640, 480) => Some(Timing {
+                pixclk: cmds::Pcsr::Pdatl as u8 | cmds::Pcsr::Clk_2 as u8,
+                hsync_nondisp: 60,
+                hsync_start: 10,
+                hsync_pw: 18,
+                hsync_finetune: 0,
+                vsync_nondisp: 18,
+                vsync_start: 10,
+                vsync_pw: 2,
+            }),
+            (800, 480) => Some(Timing {
+                pixclk: cmds::Pcsr::Pdatl as u8 | cmds::Pcsr::Clk_2 as u8,
+                hsync_nondisp: 26,
+                hsync_start: 32,
+                hsync_pw: 96,
+                hsync_finetune: 0,
+                vsync_nondisp: 32,
+                vsync_start: 23,
+                vsync_pw: 2,
+            }),
+            _ => None,
+        }
+    }
 }
 
-impl<SPI, P, O1, O2> Write for RA8875<SPI, P, O1, O2>
+impl<SPI, P, O> Write for RA8875<SPI, P, O>
 where
-    SPI: FullDuplex<u8>,
+    SPI: Interface,
     P: InputPin,
-    O1: OutputPin,
-    O2: OutputPin,
+    O: OutputPin,
 {
     fn write_str(&mut self, s: &str) -> fmt::Result {
         match self.mode {
-            Mode::Text => {
-                block!(self.write_command(Register::Mrwc as u8)).ok();
-                for c in s.as_bytes() {
-                    block!(self.write_data(*c)).ok();
-                }
-                Ok(())
-            }
+            Mode::Text => self.write_text(s).map_err(|_| fmt::Error),
             Mode::Graphics => Err(fmt::Error),
         }
     }
 }
 
+#[cfg(feature = "embedded-graphics")]
 pub fn to_coord(p: Point) -> Coord {
     (p.x as i16, p.y as i16)
 }
 
-impl<SPI, P, O1, O2> OriginDimensions for RA8875<SPI, P, O1, O2>
+/// The size is reported in the rotated frame, so 90 and 270 degree rotations swap width and
+/// height.
+#[cfg(feature = "embedded-graphics")]
+impl<SPI, P, O> OriginDimensions for RA8875<SPI, P, O>
 where
-    SPI: FullDuplex<u8>,
+    SPI: Interface,
     P: InputPin,
-    O1: OutputPin,
-    O2: OutputPin,
+    O: OutputPin,
 {
     fn size(&self) -> Size {
-        Size::new(self.dims.0 as u32, self.dims.1 as u32)
+        let (width, height) = self.rotated_dims();
+        Size::new(width, height)
     }
 }
 
-impl<SPI, P, O1, O2> DrawTarget for RA8875<SPI, P, O1, O2>
+/// Drawing happens in the frame set by `set_rotation`; coordinates are mapped back to the
+/// panel's native orientation on their way to the graphics cursor and shape registers.
+#[cfg(feature = "embedded-graphics")]
+impl<SPI, P, O> DrawTarget for RA8875<SPI, P, O>
 where
-    SPI: FullDuplex<u8>,
+    SPI: Interface,
     P: InputPin,
-    O1: OutputPin,
-    O2: OutputPin,
+    O: OutputPin,
 {
     type Color = Rgb565;
-    type Error = SpiError<SPI>;
+    type Error = Error<SpiError<SPI>, PinError<P>>;
 
     fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
     {
-        let bounding_box =
-            primitives::Rectangle::new(Point::new(0, 0), Size::new(self.dims.0, self.dims.1));
+        // Horizontal runs of adjacent pixels are buffered and streamed with a single cursor
+        // seek, relying on the chip advancing the write cursor after every pixel. Glyphs of a
+        // `MonoTextStyle` without a background color arrive here as runs of foreground pixels.
+        const RUN_LEN: usize = 64;
+        let bounding_box = primitives::Rectangle::new(Point::new(0, 0), self.size());
+        let mut run = [0_u16; RUN_LEN];
+        let mut len = 0;
+        let mut next: Option<Point> = None;
+        let mut seek: Option<Point> = None;
         for Pixel(coord, color) in pixels.into_iter() {
-            if bounding_box.contains(coord) {
-                self.draw_point((coord.x as i16, coord.y as i16), color.into_storage())?;
+            if !bounding_box.contains(coord) {
+                continue;
             }
+            if next != Some(coord) || len == RUN_LEN {
+                self.flush_run(seek.take(), &run[..len])?;
+                len = 0;
+                if next != Some(coord) {
+                    seek = Some(coord);
+                }
+            }
+            run[len] = color.into_storage();
+            len += 1;
+            next = Some(coord + Point::new(1, 0));
         }
-        Ok(())
+        self.flush_run(seek, &run[..len])
     }
 
     fn clear(&mut self, color: Rgb565) -> Result<(), Self::Error>
@@ -1053,22 +4517,51 @@ where
     where
         I: IntoIterator<Item = Self::Color>,
     {
-        let point_color_pairs = area.points().zip(colors);
-
-        let mut last_y = None;
-        for (point, color) in point_color_pairs {
-            if Some(point.y) != last_y {
-                self.cs.set_high().ok().unwrap();
-                last_y = Some(point.y);
-                self.set_cursor(to_coord(point))?;
-                block!(self.write_command(Register::Mrwc as u8))?;
-                self.cs.set_low().ok().unwrap();
-                self.spi_send(Command::DataWrite as u8)?;
+        // Only the on-screen part of the area is written; colors for clipped pixels are
+        // consumed and discarded so the rest stay aligned with their points.
+        let visible = area.intersection(&primitives::Rectangle::new(Point::zero(), self.size()));
+        if visible.is_zero_sized() {
+            return Ok(());
+        }
+
+        // Styled primitives often fill with a single color. Look ahead for as long as the
+        // colors match; if they all do, one hardware rectangle replaces the whole stream.
+        let mut colors = colors.into_iter();
+        let first = match colors.next() {
+            Some(color) => color,
+            None => return Ok(()),
+        };
+        let total = area.size.width as usize * area.size.height as usize;
+        let mut same = 1;
+        let mut different = None;
+        while same < total {
+            match colors.next() {
+                Some(color) if color == first => same += 1,
+                other => {
+                    different = other;
+                    break;
+                }
             }
-            // self.draw_point(to_coord(point), color.into_storage());
-            self.spi_send((color.into_storage() >> 8) as u8)?;
-            self.spi_send(color.into_storage() as u8)?;
         }
+        if same == total {
+            return self.fill_solid(area, first);
+        }
+        let colors = iter::repeat(first)
+            .take(same)
+            .chain(different)
+            .chain(colors);
+
+        // Stream the visible part like `draw_image`, in one memory write where the rotation
+        // lets the chip wrap rows itself. This is the path embedded-graphics takes for glyphs
+        // of a `MonoTextStyle` with a background color, where a cursor seek per row would cost
+        // as much as the row itself.
+        let visible_colors = area
+            .points()
+            .zip(colors)
+            .filter(|(point, _)| visible.contains(*point))
+            .map(|(_, color)| color.into_storage());
+        let size = (visible.size.width as u16, visible.size.height as u16);
+        self.stream_region(to_coord(visible.top_left), size, visible_colors)?;
         Ok(())
     }
 
@@ -1077,10 +4570,11 @@ where
         area: &primitives::Rectangle,
         color: Self::Color,
     ) -> Result<(), Self::Error> {
+        let area = area.intersection(&primitives::Rectangle::new(Point::zero(), self.size()));
         if let Some(bottom_right) = area.bottom_right() {
             self.draw_rect(
-                to_coord(bottom_right),
                 to_coord(area.top_left),
+                to_coord(bottom_right),
                 color.into_storage(),
                 true,
             )
@@ -0,0 +1,33 @@
+//! Named RGB565 colors and a compile-time color constructor.
+//!
+//! The constants are RGB565 words, the color type every drawing method accepts,
+//! so `palette::ORANGE` can be passed anywhere a `u16` or `Color` is.
+
+/// Packs 8-bit red, green, and blue channels into an RGB565 word, dropping the low bits of
+/// each channel.
+pub const fn rgb(r: u8, g: u8, b: u8) -> u16 {
+    ((r as u16 >> 3) << 11) | ((g as u16 >> 2) << 5) | (b as u16 >> 3)
+}
+
+// The 16 VGA/web colors.
+pub const BLACK: u16 = rgb(0, 0, 0);
+pub const NAVY: u16 = rgb(0, 0, 128);
+pub const GREEN: u16 = rgb(0, 128, 0);
+pub const TEAL: u16 = rgb(0, 128, 128);
+pub const MAROON: u16 = rgb(128, 0, 0);
+pub const PURPLE: u16 = rgb(128, 0, 128);
+pub const OLIVE: u16 = rgb(128, 128, 0);
+pub const SILVER: u16 = rgb(192, 192, 192);
+pub const GRAY: u16 = rgb(128, 128, 128);
+pub const BLUE: u16 = rgb(0, 0, 255);
+pub const LIME: u16 = rgb(0, 255, 0);
+pub const AQUA: u16 = rgb(0, 255, 255);
+pub const RED: u16 = rgb(255, 0, 0);
+pub const FUCHSIA: u16 = rgb(255, 0, 255);
+pub const YELLOW: u16 = rgb(255, 255, 0);
+pub const WHITE: u16 = rgb(255, 255, 255);
+
+// A few extras that come up often in UIs.
+pub const ORANGE: u16 = rgb(255, 165, 0);
+pub const DARK_GRAY: u16 = rgb(64, 64, 64);
+pub const LIGHT_GRAY: u16 = rgb(211, 211, 211);
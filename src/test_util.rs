@@ -0,0 +1,281 @@
+//! Mock peripherals for testing code that drives the display, without hardware.
+//!
+//! [`MockSpi`] implements the `embedded-hal` 0.2 `FullDuplex` trait and records every byte
+//! the driver sends, split into chip-select frames by the pin from
+//! [`MockSpi::chip_select`]. The log can be read back either as raw frames or decoded into
+//! register-level [`Transaction`]s, so tests can assert the exact sequence written to the
+//! chip:
+//!
+//! ```
+//! use ra8875::test_util::{MockPin, MockSpi};
+//! use ra8875::RA8875;
+//!
+//! let spi = MockSpi::new();
+//! let cs = spi.chip_select();
+//! let mut display =
+//!     RA8875::new_legacy(spi.clone(), (800, 480), MockPin::high(), cs, MockPin::high());
+//! display.draw_point((1, 2), 0xFFFFu16).unwrap();
+//! assert_eq!(spi.register_writes()[0], (0x46, 1)); // CURH0
+//! ```
+//!
+//! The mocks are shared handles: clones see the same log, so a test keeps one after moving
+//! the other into the driver. This module needs `std` and is only built with the `test-util`
+//! feature.
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::rc::Rc;
+use std::vec::Vec;
+
+use hal::digital::v2::{InputPin, OutputPin};
+use hal::spi::FullDuplex;
+use nb;
+
+use Command;
+
+/// A register-level view of one chip-select frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Transaction {
+    /// A register address was selected.
+    Command(u8),
+    /// Data was written to the selected register. Streamed pixel data arrives as one write
+    /// holding every byte of the stream.
+    Write(Vec<u8>),
+    /// The selected register was read, returning the given value.
+    ///
+    /// Only the reply is recorded, not the dummy byte the driver clocked out to receive it,
+    /// so a read can't reveal a wrong byte sent during it.
+    Read(u8),
+    /// The status register was read, returning the given value. Like `Read`, this records
+    /// only the reply.
+    Status(u8),
+    /// A frame that doesn't start with a valid command byte.
+    Unknown(Vec<u8>),
+}
+
+struct Pin {
+    high: Cell<bool>,
+    /// Number of high-to-low transitions, which start a new frame on a chip-select pin.
+    selects: Cell<u32>,
+}
+
+/// A GPIO pin that can be read, driven, and inspected.
+///
+/// Used as the `ready` pin it reads as not busy when high, the default polarity. Used as the
+/// `rst` pin its level can be checked after a reset.
+#[derive(Clone)]
+pub struct MockPin {
+    pin: Rc<Pin>,
+}
+
+impl MockPin {
+    /// Creates a pin at the given level.
+    pub fn new(high: bool) -> Self {
+        MockPin {
+            pin: Rc::new(Pin {
+                high: Cell::new(high),
+                selects: Cell::new(0),
+            }),
+        }
+    }
+
+    /// Creates a pin that starts high.
+    pub fn high() -> Self {
+        Self::new(true)
+    }
+
+    /// Creates a pin that starts low.
+    pub fn low() -> Self {
+        Self::new(false)
+    }
+
+    /// Sets the level seen by the driver when it reads the pin.
+    pub fn set_level(&self, high: bool) {
+        self.pin.high.set(high);
+    }
+
+    /// The pin's current level.
+    pub fn level(&self) -> bool {
+        self.pin.high.get()
+    }
+}
+
+impl InputPin for MockPin {
+    type Error = Infallible;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.pin.high.get())
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        Ok(!self.pin.high.get())
+    }
+}
+
+impl OutputPin for MockPin {
+    type Error = Infallible;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        if self.pin.high.get() {
+            self.pin.selects.set(self.pin.selects.get().wrapping_add(1));
+        }
+        self.pin.high.set(false);
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.pin.high.set(true);
+        Ok(())
+    }
+}
+
+struct Bus {
+    cs: MockPin,
+    /// The chip-select count the last frame was opened at.
+    frame_select: Option<u32>,
+    frames: Vec<Vec<u8>>,
+    replies: VecDeque<u8>,
+    reply: u8,
+}
+
+/// A recording `FullDuplex` SPI peripheral.
+///
+/// Reads return the values queued with [`queue_read`](MockSpi::queue_read) in order, and 0
+/// once the queue is empty, which the driver sees as an idle chip.
+#[derive(Clone)]
+pub struct MockSpi {
+    bus: Rc<RefCell<Bus>>,
+}
+
+impl MockSpi {
+    /// Creates a peripheral with an empty log.
+    pub fn new() -> Self {
+        MockSpi {
+            bus: Rc::new(RefCell::new(Bus {
+                cs: MockPin::high(),
+                frame_select: None,
+                frames: Vec::new(),
+                replies: VecDeque::new(),
+                reply: 0,
+            })),
+        }
+    }
+
+    /// Returns the chip-select pin to hand to the driver. Each time it is driven low, the
+    /// bytes that follow are logged as a new frame.
+    pub fn chip_select(&self) -> MockPin {
+        self.bus.borrow().cs.clone()
+    }
+
+    /// Queues a value for the next register or status read to return.
+    pub fn queue_read(&self, value: u8) {
+        self.bus.borrow_mut().replies.push_back(value);
+    }
+
+    /// Forgets everything logged so far, for example the writes made during setup.
+    pub fn clear(&self) {
+        let mut bus = self.bus.borrow_mut();
+        bus.frames.clear();
+        bus.frame_select = None;
+    }
+
+    /// Every byte sent, grouped by chip-select frame.
+    pub fn frames(&self) -> Vec<Vec<u8>> {
+        self.bus.borrow().frames.clone()
+    }
+
+    /// The logged frames decoded into register-level transactions.
+    pub fn transactions(&self) -> Vec<Transaction> {
+        self.bus
+            .borrow()
+            .frames
+            .iter()
+            .map(|frame| decode(frame))
+            .collect()
+    }
+
+    /// Each register write as `(register, value)`, in the order they were made.
+    ///
+    /// Reads, status polls, and streamed data are left out, so only a command followed by a
+    /// single-byte write counts.
+    pub fn register_writes(&self) -> Vec<(u8, u8)> {
+        let mut writes = Vec::new();
+        let mut register = None;
+        for transaction in self.transactions() {
+            match transaction {
+                Transaction::Command(address) => register = Some(address),
+                Transaction::Write(ref data) if data.len() == 1 => {
+                    if let Some(address) = register {
+                        writes.push((address, data[0]));
+                    }
+                }
+                _ => {}
+            }
+        }
+        writes
+    }
+
+    /// The value last written to `register`, if it was written at all.
+    pub fn last_write(&self, register: u8) -> Option<u8> {
+        self.register_writes()
+            .iter()
+            .rev()
+            .find(|&&(address, _)| address == register)
+            .map(|&(_, value)| value)
+    }
+}
+
+impl Default for MockSpi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FullDuplex<u8> for MockSpi {
+    type Error = Infallible;
+
+    fn read(&mut self) -> nb::Result<u8, Self::Error> {
+        Ok(self.bus.borrow().reply)
+    }
+
+    fn send(&mut self, byte: u8) -> nb::Result<(), Self::Error> {
+        let mut bus = self.bus.borrow_mut();
+        let selects = bus.cs.pin.selects.get();
+        if bus.frame_select != Some(selects) {
+            bus.frame_select = Some(selects);
+            bus.frames.push(Vec::new());
+        }
+        let is_read = match bus.frames.last() {
+            Some(frame) => {
+                frame.len() == 1
+                    && (frame[0] == Command::DataRead as u8 || frame[0] == Command::CmdRead as u8)
+            }
+            None => false,
+        };
+        // Reads are logged with the value returned rather than the dummy byte sent.
+        let logged = if is_read {
+            bus.reply = bus.replies.pop_front().unwrap_or(0);
+            bus.reply
+        } else {
+            bus.reply = 0;
+            byte
+        };
+        if let Some(frame) = bus.frames.last_mut() {
+            frame.push(logged);
+        }
+        Ok(())
+    }
+}
+
+fn decode(frame: &[u8]) -> Transaction {
+    match frame {
+        [command, data] if *command == Command::CmdWrite as u8 => Transaction::Command(*data),
+        [command, value] if *command == Command::DataRead as u8 => Transaction::Read(*value),
+        [command, value] if *command == Command::CmdRead as u8 => Transaction::Status(*value),
+        [command, data @ ..] if *command == Command::DataWrite as u8 && !data.is_empty() => {
+            Transaction::Write(data.to_vec())
+        }
+        _ => Transaction::Unknown(frame.to_vec()),
+    }
+}
@@ -0,0 +1,225 @@
+//! Touch panel configuration, calibration, and stroke recording.
+
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::prelude::{Point, Size};
+
+/// Largest value the 10-bit touch ADC reports.
+pub const TOUCH_ADC_MAX: i32 = 0x3FF;
+
+/// How long the touch ADC samples, in system clocks, before converting.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TouchSampleTime {
+    Clk512 = 0x00,
+    Clk1024 = 0x10,
+    Clk2048 = 0x20,
+    Clk4096 = 0x30,
+    Clk8192 = 0x40,
+    Clk16384 = 0x50,
+    Clk32768 = 0x60,
+    Clk65536 = 0x70,
+}
+
+/// Divider from the system clock to the touch ADC clock.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TouchAdcClock {
+    Div1 = 0x00,
+    Div2 = 0x01,
+    Div4 = 0x02,
+    Div8 = 0x03,
+    Div16 = 0x04,
+    Div32 = 0x05,
+    Div64 = 0x06,
+    Div128 = 0x07,
+}
+
+/// Touch controller settings for `enable_touch_manual`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TouchConfig {
+    pub sample_time: TouchSampleTime,
+    pub adc_clock: TouchAdcClock,
+    /// Use an external ADC reference voltage instead of the internal one.
+    pub external_vref: bool,
+    /// Enable the hardware de-bounce circuit.
+    pub debounce: bool,
+}
+
+impl Default for TouchConfig {
+    /// The settings `enable_touch` uses.
+    fn default() -> Self {
+        TouchConfig {
+            sample_time: TouchSampleTime::Clk16384,
+            adc_clock: TouchAdcClock::Div32,
+            external_vref: false,
+            debounce: true,
+        }
+    }
+}
+
+/// Maps raw touch ADC readings to screen coordinates with an affine transform.
+///
+/// Screen coordinates are computed as `x = (a * tx + b * ty + c) / divisor` and
+/// `y = (d * tx + e * ty + f) / divisor`, which covers scaling, offset, skew, and a
+/// panel mounted at a slight angle to the display.
+#[cfg(feature = "embedded-graphics")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TouchCalibration {
+    pub a: i64,
+    pub b: i64,
+    pub c: i64,
+    pub d: i64,
+    pub e: i64,
+    pub f: i64,
+    pub divisor: i64,
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl TouchCalibration {
+    /// A calibration that linearly maps readings between `min` and `max` onto a screen of
+    /// `size`. Returns `None` if the bounds don't span a range on both axes.
+    pub fn from_bounds(min: Point, max: Point, size: Size) -> Option<Self> {
+        let dx = (max.x - min.x) as i64;
+        let dy = (max.y - min.y) as i64;
+        if dx == 0 || dy == 0 {
+            return None;
+        }
+        let w = size.width as i64 - 1;
+        let h = size.height as i64 - 1;
+        Some(TouchCalibration {
+            a: w * dy,
+            b: 0,
+            c: -(min.x as i64) * w * dy,
+            d: 0,
+            e: h * dx,
+            f: -(min.y as i64) * h * dx,
+            divisor: dx * dy,
+        })
+    }
+
+    /// Computes a calibration from three taps, pairing the screen position of each target
+    /// with the raw reading taken while it was pressed. The targets should be spread out and
+    /// must not lie on a line; `None` is returned if they do.
+    pub fn from_points(screen: [Point; 3], touch: [Point; 3]) -> Option<Self> {
+        let (x0, y0) = (touch[0].x as i64, touch[0].y as i64);
+        let (x1, y1) = (touch[1].x as i64, touch[1].y as i64);
+        let (x2, y2) = (touch[2].x as i64, touch[2].y as i64);
+        let (sx0, sy0) = (screen[0].x as i64, screen[0].y as i64);
+        let (sx1, sy1) = (screen[1].x as i64, screen[1].y as i64);
+        let (sx2, sy2) = (screen[2].x as i64, screen[2].y as i64);
+
+        let divisor = (x0 - x2) * (y1 - y2) - (x1 - x2) * (y0 - y2);
+        if divisor == 0 {
+            return None;
+        }
+        Some(TouchCalibration {
+            a: (sx0 - sx2) * (y1 - y2) - (sx1 - sx2) * (y0 - y2),
+            b: (x0 - x2) * (sx1 - sx2) - (sx0 - sx2) * (x1 - x2),
+            c: y0 * (x2 * sx1 - x1 * sx2) + y1 * (x0 * sx2 - x2 * sx0) + y2 * (x1 * sx0 - x0 * sx1),
+            d: (sy0 - sy2) * (y1 - y2) - (sy1 - sy2) * (y0 - y2),
+            e: (x0 - x2) * (sy1 - sy2) - (sy0 - sy2) * (x1 - x2),
+            f: y0 * (x2 * sy1 - x1 * sy2) + y1 * (x0 * sy2 - x2 * sy0) + y2 * (x1 * sy0 - x0 * sy1),
+            divisor,
+        })
+    }
+
+    /// Applies the transform to a raw reading.
+    pub fn apply(&self, raw: Point) -> Point {
+        let (tx, ty) = (raw.x as i64, raw.y as i64);
+        let x = (self.a * tx + self.b * ty + self.c) / self.divisor;
+        let y = (self.d * tx + self.e * ty + self.f) / self.divisor;
+        Point::new(x as i32, y as i32)
+    }
+}
+
+/// A calibrated touch point and the caller's timestamp for when it was read.
+#[cfg(feature = "embedded-graphics")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TouchSample {
+    pub point: Point,
+    /// Time of the reading in whatever unit the caller passes to `record_touch`.
+    pub timestamp: u32,
+}
+
+/// A ring buffer of the last `N` touch samples of a stroke, filled by `record_touch`.
+///
+/// Once full, each new sample replaces the oldest one.
+#[cfg(feature = "embedded-graphics")]
+#[derive(Copy, Clone, Debug)]
+pub struct TouchStroke<const N: usize> {
+    samples: [TouchSample; N],
+    start: usize,
+    len: usize,
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl<const N: usize> TouchStroke<N> {
+    /// Creates an empty stroke.
+    pub const fn new() -> Self {
+        TouchStroke {
+            samples: [TouchSample {
+                point: Point::zero(),
+                timestamp: 0,
+            }; N],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Appends a sample, dropping the oldest one if the buffer is full.
+    pub fn push(&mut self, sample: TouchSample) {
+        if N == 0 {
+            return;
+        }
+        if self.len < N {
+            self.samples[(self.start + self.len) % N] = sample;
+            self.len += 1;
+        } else {
+            self.samples[self.start] = sample;
+            self.start = (self.start + 1) % N;
+        }
+    }
+
+    /// Number of samples held.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Discards all samples, e.g. once a finished stroke has been processed.
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+
+    /// The held samples, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &TouchSample> + '_ {
+        (0..self.len).map(move |i| &self.samples[(self.start + i) % N])
+    }
+
+    /// The most recent sample.
+    pub fn last(&self) -> Option<&TouchSample> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(&self.samples[(self.start + self.len - 1) % N])
+        }
+    }
+
+    /// Whether the stroke has samples but none newer than `timeout` before `now`, meaning
+    /// the finger has lifted and the stroke can be finalized. Timestamps may wrap around.
+    pub fn is_idle(&self, now: u32, timeout: u32) -> bool {
+        match self.last() {
+            Some(sample) => now.wrapping_sub(sample.timestamp) >= timeout,
+            None => false,
+        }
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl<const N: usize> Default for TouchStroke<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
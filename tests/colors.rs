@@ -0,0 +1,29 @@
+//! Checks the RGB565 color helpers, which don't touch the hardware.
+
+extern crate ra8875;
+
+use ra8875::{blend_rgb565, dim_rgb565};
+
+#[test]
+fn blend_endpoints_return_the_inputs() {
+    assert_eq!(blend_rgb565(0xF800, 0x07E0, 0), 0xF800);
+    assert_eq!(blend_rgb565(0xF800, 0x07E0, 255), 0x07E0);
+}
+
+#[test]
+fn blend_midpoint_mixes_each_channel() {
+    // Halfway from black to white rounds each channel up: 16 of 31, 32 of 63, 16 of 31.
+    assert_eq!(
+        blend_rgb565(0x0000, 0xFFFF, 128),
+        (16 << 11) | (32 << 5) | 16
+    );
+    // Channels are mixed independently, so red fading into blue passes through purple.
+    assert_eq!(blend_rgb565(0xF800, 0x001F, 128), (15 << 11) | 16);
+}
+
+#[test]
+fn dim_scales_brightness() {
+    assert_eq!(dim_rgb565(0xFFFF, 0), 0x0000);
+    assert_eq!(dim_rgb565(0x1234, 255), 0x1234);
+    assert_eq!(dim_rgb565(0xFFFF, 128), (16 << 11) | (32 << 5) | 16);
+}
@@ -0,0 +1,955 @@
+//! Checks the register sequences the driver sends, using the `test-util` mocks.
+
+extern crate embedded_graphics;
+extern crate embedded_hal;
+extern crate embedded_hal_1;
+extern crate ra8875;
+
+use embedded_graphics::pixelcolor::raw::RawU16;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+use embedded_hal::blocking::delay::DelayMs;
+use embedded_hal_1::{digital, spi};
+use ra8875::interface::LegacySpi;
+use ra8875::test_util::{MockPin, MockSpi, Transaction};
+use ra8875::ExclusiveSpi;
+use ra8875::RA8875;
+use std::cell::RefCell;
+use std::convert::Infallible;
+use std::rc::Rc;
+
+type Display = RA8875<LegacySpi<MockSpi, MockPin>, MockPin, MockPin>;
+
+fn display(dims: (u32, u32)) -> (Display, MockSpi) {
+    let spi = MockSpi::new();
+    let cs = spi.chip_select();
+    let display = RA8875::new_legacy(spi.clone(), dims, MockPin::high(), cs, MockPin::high());
+    (display, spi)
+}
+
+/// The shape corner registers, SHAPE_START_X0 through SHAPE_END_Y1, written with the given
+/// hardware coordinates.
+fn shape_corners((x0, y0): (u16, u16), (x1, y1): (u16, u16)) -> Vec<(u8, u8)> {
+    vec![
+        (0x91, x0 as u8),
+        (0x92, (x0 >> 8) as u8),
+        (0x93, y0 as u8),
+        (0x94, (y0 >> 8) as u8),
+        (0x95, x1 as u8),
+        (0x96, (x1 >> 8) as u8),
+        (0x97, y1 as u8),
+        (0x98, (y1 >> 8) as u8),
+    ]
+}
+
+#[test]
+fn init_800x480() {
+    let (mut display, spi) = display((800, 480));
+    display.init().unwrap();
+
+    let expected = vec![
+        (0x10, 0x0C), // SYSR: 16bpp, 8-bit MCU interface
+        (0x04, 0x81), // PCSR: falling edge, system clock / 2
+        (0x14, 0x63), // HDWR: 800 / 8 - 1
+        (0x15, 0x00), // HNDFTR
+        (0x16, 0x03), // HNDR
+        (0x17, 0x03), // HSTR
+        (0x18, 0x0B), // HPWR
+        (0x19, 0xDF), // VDHR0: 479
+        (0x1A, 0x01), // VDHR1
+        (0x1B, 0x1F), // VNDR0
+        (0x1C, 0x00), // VNDR1
+        (0x1D, 0x16), // VSTR0
+        (0x1E, 0x00), // VSTR1
+        (0x1F, 0x01), // VPWR
+        (0x30, 0x00), // active window: 0, 0 to 799, 479
+        (0x31, 0x00),
+        (0x34, 0x1F),
+        (0x35, 0x03),
+        (0x32, 0x00),
+        (0x33, 0x00),
+        (0x36, 0xDF),
+        (0x37, 0x01),
+        (0x8E, 0x80), // MCLR: clear the full window
+    ];
+    assert_eq!(spi.register_writes(), expected);
+}
+
+#[test]
+fn init_480x272() {
+    let (mut display, spi) = display((480, 272));
+    display.init().unwrap();
+
+    let expected = vec![
+        (0x10, 0x0C), // SYSR
+        (0x04, 0x82), // PCSR: falling edge, system clock / 4
+        (0x14, 0x3B), // HDWR: 480 / 8 - 1
+        (0x15, 0x00), // HNDFTR
+        (0x16, 0x01), // HNDR
+        (0x17, 0x00), // HSTR
+        (0x18, 0x05), // HPWR
+        (0x19, 0x0F), // VDHR0: 271
+        (0x1A, 0x01), // VDHR1
+        (0x1B, 0x02), // VNDR0
+        (0x1C, 0x00), // VNDR1
+        (0x1D, 0x07), // VSTR0
+        (0x1E, 0x00), // VSTR1
+        (0x1F, 0x09), // VPWR
+        (0x30, 0x00), // active window: 0, 0 to 479, 271
+        (0x31, 0x00),
+        (0x34, 0xDF),
+        (0x35, 0x01),
+        (0x32, 0x00),
+        (0x33, 0x00),
+        (0x36, 0x0F),
+        (0x37, 0x01),
+        (0x8E, 0x80), // MCLR
+    ];
+    assert_eq!(spi.register_writes(), expected);
+}
+
+#[test]
+fn init_320x240() {
+    let (mut display, spi) = display((320, 240));
+    display.init().unwrap();
+
+    let expected = vec![
+        (0x10, 0x0C), // SYSR
+        (0x04, 0x83), // PCSR: falling edge, system clock / 8
+        (0x14, 0x27), // HDWR: 320 / 8 - 1
+        (0x15, 0x00), // HNDFTR
+        (0x16, 0x01), // HNDR
+        (0x17, 0x00), // HSTR
+        (0x18, 0x05), // HPWR
+        (0x19, 0xEF), // VDHR0: 239
+        (0x1A, 0x00), // VDHR1
+        (0x1B, 0x02), // VNDR0
+        (0x1C, 0x00), // VNDR1
+        (0x1D, 0x07), // VSTR0
+        (0x1E, 0x00), // VSTR1
+        (0x1F, 0x09), // VPWR
+        (0x30, 0x00), // active window: 0, 0 to 319, 239
+        (0x31, 0x00),
+        (0x34, 0x3F),
+        (0x35, 0x01),
+        (0x32, 0x00),
+        (0x33, 0x00),
+        (0x36, 0xEF),
+        (0x37, 0x00),
+        (0x8E, 0x80), // MCLR
+    ];
+    assert_eq!(spi.register_writes(), expected);
+}
+
+#[test]
+fn init_640x480() {
+    let (mut display, spi) = display((640, 480));
+    display.init().unwrap();
+
+    let expected = vec![
+        (0x10, 0x0C), // SYSR
+        (0x04, 0x81), // PCSR: falling edge, system clock / 2
+        (0x14, 0x4F), // HDWR: 640 / 8 - 1
+        (0x15, 0x00), // HNDFTR
+        (0x16, 0x07), // HNDR
+        (0x17, 0x00), // HSTR
+        (0x18, 0x01), // HPWR
+        (0x19, 0xDF), // VDHR0: 479
+        (0x1A, 0x01), // VDHR1
+        (0x1B, 0x11), // VNDR0
+        (0x1C, 0x00), // VNDR1
+        (0x1D, 0x09), // VSTR0
+        (0x1E, 0x00), // VSTR1
+        (0x1F, 0x01), // VPWR
+        (0x30, 0x00), // active window: 0, 0 to 639, 479
+        (0x31, 0x00),
+        (0x34, 0x7F),
+        (0x35, 0x02),
+        (0x32, 0x00),
+        (0x33, 0x00),
+        (0x36, 0xDF),
+        (0x37, 0x01),
+        (0x8E, 0x80), // MCLR
+    ];
+    assert_eq!(spi.register_writes(), expected);
+}
+
+#[test]
+fn init_waits_for_memory_clear() {
+    let (mut display, spi) = display((800, 480));
+    // FNCR1, then two polls of MCLR before the clear finishes.
+    spi.queue_read(0x00);
+    spi.queue_read(0x80);
+    spi.queue_read(0x80);
+    display.init().unwrap();
+
+    let transactions = spi.transactions();
+    let tail = &transactions[transactions.len() - 6..];
+    assert_eq!(
+        tail,
+        &[
+            Transaction::Command(0x8E),
+            Transaction::Read(0x80),
+            Transaction::Command(0x8E),
+            Transaction::Read(0x80),
+            Transaction::Command(0x8E),
+            Transaction::Read(0x00),
+        ]
+    );
+}
+
+#[test]
+fn init_rejects_unsupported_dimensions() {
+    let (mut display, spi) = display((123, 45));
+    assert!(display.init().is_err());
+    assert!(spi.frames().is_empty());
+}
+
+#[test]
+fn draw_rect_outline() {
+    let (mut display, spi) = display((800, 480));
+    display
+        .draw_rect((10, 20), (300, 400), 0xF800u16, false)
+        .unwrap();
+
+    let mut expected = shape_corners((10, 20), (300, 400));
+    expected.extend(vec![
+        (0x63, 0x1F), // red
+        (0x64, 0x00), // green
+        (0x65, 0x00), // blue
+        (0x90, 0x90), // DCR: start a square
+    ]);
+    assert_eq!(spi.register_writes(), expected);
+}
+
+#[test]
+fn draw_rect_filled_normalizes_corners() {
+    let (mut display, spi) = display((800, 480));
+    display
+        .draw_rect((300, 400), (10, 20), 0x07E0u16, true)
+        .unwrap();
+
+    let mut expected = shape_corners((10, 20), (300, 400));
+    expected.extend(vec![
+        (0x63, 0x00),
+        (0x64, 0x3F),
+        (0x65, 0x00),
+        (0x90, 0xB0), // DCR: start a filled square
+    ]);
+    assert_eq!(spi.register_writes(), expected);
+}
+
+#[test]
+fn draw_circle() {
+    let (mut display, spi) = display((800, 480));
+    display
+        .draw_circle((400, 240), 100, 0x001Fu16, true)
+        .unwrap();
+
+    let expected = vec![
+        (0x99, 0x90), // CIRCLE_X: 400
+        (0x9A, 0x01),
+        (0x9B, 0xF0), // CIRCLE_Y: 240
+        (0x9C, 0x00),
+        (0x9D, 100), // radius
+        (0x63, 0x00),
+        (0x64, 0x00),
+        (0x65, 0x1F),
+        (0x90, 0x60), // DCR: start a filled circle
+    ];
+    assert_eq!(spi.register_writes(), expected);
+}
+
+#[test]
+fn draw_circle_rejects_large_radius() {
+    let (mut display, spi) = display((800, 480));
+    match display.draw_circle((400, 240), 300, 0u16, false) {
+        Err(ra8875::Error::RadiusOutOfRange) => {}
+        other => panic!("expected RadiusOutOfRange, got {:?}", other),
+    }
+    assert!(display.draw_circle((400, 240), 256, 0u16, false).is_err());
+    assert!(spi.frames().is_empty());
+}
+
+#[test]
+fn draw_circle_accepts_largest_radius() {
+    let (mut display, spi) = display((800, 480));
+    display.draw_circle((400, 240), 255, 0u16, false).unwrap();
+    assert_eq!(spi.last_write(0x9D), Some(0xFF));
+}
+
+#[test]
+fn bte_solid_fill_maps_rotated_region() {
+    let (mut display, spi) = display((800, 480));
+    display.set_rotation(ra8875::Rotation::Rotate90).unwrap();
+    spi.clear();
+    display.bte_solid_fill((10, 20), 30, 5, 0xFFFFu16).unwrap();
+
+    // Rotated x 10..=39, y 20..=24 is hardware x 775..=779, y 10..=39.
+    let writes = spi.register_writes();
+    let region: Vec<_> = writes
+        .iter()
+        .filter(|&&(register, _)| (0x58..=0x5F).contains(&register))
+        .cloned()
+        .collect();
+    assert_eq!(
+        region,
+        vec![
+            (0x58, 0x07),
+            (0x59, 0x03),
+            (0x5A, 10),
+            (0x5B, 0),
+            (0x5C, 5),
+            (0x5D, 0),
+            (0x5E, 30),
+            (0x5F, 0),
+        ]
+    );
+}
+
+#[test]
+fn draw_text_sets_colors() {
+    let (mut display, spi) = display((800, 480));
+    display
+        .draw_text((0, 0), "A", 0xFFFFu16, Some(0x0000))
+        .unwrap();
+
+    let writes = spi.register_writes();
+    let colors: Vec<_> = writes
+        .iter()
+        .filter(|&&(register, _)| (0x60..=0x65).contains(&register))
+        .cloned()
+        .collect();
+    assert_eq!(
+        colors,
+        vec![
+            (0x63, 0x1F), // foreground: white
+            (0x64, 0x3F),
+            (0x65, 0x1F),
+            (0x60, 0x00), // background: black
+            (0x61, 0x00),
+            (0x62, 0x00),
+        ]
+    );
+}
+
+#[test]
+fn draw_text_accepts_rgb565_background() {
+    let (mut display, spi) = display((800, 480));
+    display
+        .draw_text((0, 0), "A", Rgb565::WHITE, Some(Rgb565::BLUE))
+        .unwrap();
+
+    assert_eq!(spi.last_write(0x60), Some(0x00));
+    assert_eq!(spi.last_write(0x61), Some(0x00));
+    assert_eq!(spi.last_write(0x62), Some(0x1F));
+}
+
+#[test]
+fn draw_text_transparent_background() {
+    let (mut display, spi) = display((800, 480));
+    display.draw_text((0, 0), "A", 0xFFFFu16, None).unwrap();
+
+    assert_eq!(spi.last_write(0x60), None);
+    // FNCR1 bit 6 keeps the background transparent.
+    assert_eq!(spi.last_write(0x22).map(|fncr1| fncr1 & 0x40), Some(0x40));
+}
+
+#[test]
+fn fill_solid_uses_drawing_frame_corners() {
+    let (mut display, spi) = display((800, 480));
+    display
+        .fill_solid(
+            &Rectangle::new(Point::new(10, 20), Size::new(30, 40)),
+            Rgb565::WHITE,
+        )
+        .unwrap();
+
+    let writes = spi.register_writes();
+    assert_eq!(&writes[..8], &shape_corners((10, 20), (39, 59))[..]);
+    assert_eq!(writes.last(), Some(&(0x90, 0xB0)));
+}
+
+#[test]
+fn fill_solid_puts_smaller_corner_in_shape_start() {
+    let (mut display, spi) = display((800, 480));
+    // Partly off the top-left edge; the clipped rectangle must still start at its top-left.
+    display
+        .fill_solid(
+            &Rectangle::new(Point::new(-5, -5), Size::new(300, 200)),
+            Rgb565::BLACK,
+        )
+        .unwrap();
+
+    let writes = spi.register_writes();
+    assert_eq!(&writes[..8], &shape_corners((0, 0), (294, 194))[..]);
+}
+
+#[test]
+fn fill_contiguous_clips_at_right_edge() {
+    let (mut display, spi) = display((800, 480));
+    // Four pixels starting two from the right edge; only the first two are on the panel.
+    let colors = [1, 2, 3, 4].iter().map(|&c| Rgb565::from(RawU16::new(c)));
+    display
+        .fill_contiguous(&Rectangle::new(Point::new(798, 5), Size::new(4, 1)), colors)
+        .unwrap();
+
+    assert_eq!(graphics_cursors(&spi), vec![(798, 5)]);
+    assert_eq!(pixel_streams(&spi), vec![vec![0, 1, 0, 2]]);
+    let window: Vec<_> = spi
+        .register_writes()
+        .into_iter()
+        .filter(|&(register, _)| (0x30..=0x37).contains(&register))
+        .collect();
+    // The narrowed window ends at the last column, x = 799.
+    assert_eq!(&window[2..4], &[(0x34, 0x1F), (0x35, 0x03)]);
+}
+
+#[test]
+fn draw_rect_accepts_rgb565() {
+    let (mut display, spi) = display((800, 480));
+    display
+        .draw_rect((0, 0), (1, 1), Rgb565::RED, false)
+        .unwrap();
+
+    assert_eq!(spi.last_write(0x63), Some(0x1F));
+    assert_eq!(spi.last_write(0x64), Some(0x00));
+    assert_eq!(spi.last_write(0x65), Some(0x00));
+}
+
+#[test]
+fn wait_for_pll_reads_back_settings() {
+    let (mut display, spi) = display((800, 480));
+    display.set_up_pll().unwrap();
+    // The first read-back is stale, the second matches PLLC1 = 0x0A and PLLC2 = 0x02.
+    for value in &[0x00, 0x00, 0x0A, 0x02] {
+        spi.queue_read(*value);
+    }
+    display.wait_for_pll(&mut NoDelay, 5).unwrap();
+}
+
+#[test]
+fn wait_for_pll_times_out() {
+    let (mut display, _spi) = display((800, 480));
+    display.set_up_pll().unwrap();
+    assert!(display.wait_for_pll(&mut NoDelay, 5).is_err());
+}
+
+#[test]
+fn init_with_reset_waits_for_pll() {
+    let (mut display, spi) = display((800, 480));
+    spi.queue_read(0x0A);
+    spi.queue_read(0x02);
+    display.init_with_reset(&mut NoDelay).unwrap();
+
+    // The PLL is read back before anything else is configured.
+    let transactions = spi.transactions();
+    assert_eq!(
+        &transactions[..8],
+        &[
+            Transaction::Command(0x88),
+            Transaction::Write(vec![0x0A]),
+            Transaction::Command(0x89),
+            Transaction::Write(vec![0x02]),
+            Transaction::Command(0x88),
+            Transaction::Read(0x0A),
+            Transaction::Command(0x89),
+            Transaction::Read(0x02),
+        ]
+    );
+}
+
+#[test]
+fn init_with_reset_reports_unlocked_pll() {
+    let (mut display, spi) = display((800, 480));
+    match display.init_with_reset(&mut NoDelay) {
+        Err(ra8875::Error::PllUnlocked) => {}
+        _ => panic!("expected PllUnlocked"),
+    }
+    // Nothing past the PLL is configured.
+    assert_eq!(spi.last_write(0x01), None);
+}
+
+#[test]
+fn set_up_pll_checked_waits_for_lock() {
+    let (mut display, spi) = display((800, 480));
+    spi.queue_read(0x0A);
+    spi.queue_read(0x02);
+    display.set_up_pll_checked(&mut NoDelay).unwrap();
+
+    let (mut display, _spi) = self::display((800, 480));
+    match display.set_up_pll_checked(&mut NoDelay) {
+        Err(ra8875::Error::PllUnlocked) => {}
+        _ => panic!("expected PllUnlocked"),
+    }
+}
+
+#[test]
+fn wake_reports_unlocked_pll() {
+    let (mut display, spi) = display((800, 480));
+    display.set_up_pll().unwrap();
+    display.sleep().unwrap();
+    spi.clear();
+    match display.wake(&mut NoDelay) {
+        Err(ra8875::Error::PllUnlocked) => {}
+        _ => panic!("expected PllUnlocked"),
+    }
+    // Sleep is left, but the display isn't turned back on.
+    assert_eq!(
+        spi.register_writes().iter().filter(|w| w.0 == 0x01).count(),
+        1
+    );
+    assert_eq!(spi.last_write(0x01), Some(0x00));
+}
+
+struct NoDelay;
+
+impl DelayMs<u8> for NoDelay {
+    fn delay_ms(&mut self, _ms: u8) {}
+}
+
+#[test]
+fn draw_bitmap_1bpp_transparent_writes_runs() {
+    let (mut display, spi) = display((800, 480));
+    // A 10x2 bitmap: row 0 sets columns 1-2 and 8-9, row 1 is empty.
+    let data = [0b0110_0000, 0b1100_0000, 0x00, 0x00];
+    display
+        .draw_bitmap_1bpp((100, 50), (10, 2), &data, 0xFFFFu16, None)
+        .unwrap();
+
+    let cursors: Vec<_> = spi
+        .register_writes()
+        .into_iter()
+        .filter(|&(register, _)| register == 0x46)
+        .map(|(_, x)| x)
+        .collect();
+    assert_eq!(cursors, vec![101, 108]);
+    let streams: Vec<_> = spi
+        .transactions()
+        .into_iter()
+        .filter_map(|transaction| match transaction {
+            Transaction::Write(data) if data.len() > 1 => Some(data),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(streams, vec![vec![0xFF; 4], vec![0xFF; 4]]);
+}
+
+#[test]
+fn draw_bitmap_1bpp_checks_length() {
+    let (mut display, spi) = display((800, 480));
+    assert!(display
+        .draw_bitmap_1bpp((0, 0), (9, 2), &[0; 3], 0xFFFFu16, Some(0))
+        .is_err());
+    assert!(spi.frames().is_empty());
+}
+
+#[test]
+fn set_flip_keeps_other_dpcr_bits() {
+    let (mut display, spi) = display((800, 480));
+    // Two-layer mode with a vertical flip already set.
+    spi.queue_read(0x84);
+    display.set_flip(true, false).unwrap();
+
+    assert_eq!(
+        spi.transactions(),
+        vec![
+            Transaction::Command(0x20),
+            Transaction::Read(0x84),
+            Transaction::Write(vec![0x88]),
+        ]
+    );
+}
+
+/// The graphics cursor positions written, as hardware `(x, y)` pairs.
+fn graphics_cursors(spi: &MockSpi) -> Vec<(u16, u16)> {
+    let writes = spi.register_writes();
+    writes
+        .windows(4)
+        .filter(|w| w[0].0 == 0x46 && w[1].0 == 0x47 && w[2].0 == 0x48 && w[3].0 == 0x49)
+        .map(|w| {
+            (
+                w[0].1 as u16 | (w[1].1 as u16) << 8,
+                w[2].1 as u16 | (w[3].1 as u16) << 8,
+            )
+        })
+        .collect()
+}
+
+/// Every multi-byte data write, which is how pixel streams show up.
+fn pixel_streams(spi: &MockSpi) -> Vec<Vec<u8>> {
+    spi.transactions()
+        .into_iter()
+        .filter_map(|transaction| match transaction {
+            Transaction::Write(data) if data.len() > 1 => Some(data),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn draw_image_rotated_90_seeks_each_row() {
+    let (mut display, spi) = display((800, 480));
+    display.set_rotation(ra8875::Rotation::Rotate90).unwrap();
+    spi.clear();
+    display
+        .draw_image((10, 20), (3, 2), &[1, 2, 3, 4, 5, 6])
+        .unwrap();
+
+    // Rotated (10, 20) is hardware (799 - 20, 10); each rotated row is one hardware column
+    // further left.
+    assert_eq!(graphics_cursors(&spi), vec![(779, 10), (778, 10)]);
+    assert_eq!(
+        pixel_streams(&spi),
+        vec![vec![0, 1, 0, 2, 0, 3], vec![0, 4, 0, 5, 0, 6]]
+    );
+}
+
+#[test]
+fn draw_image_unrotated_streams_once() {
+    let (mut display, spi) = display((800, 480));
+    display.draw_image((10, 20), (2, 2), &[1, 2, 3, 4]).unwrap();
+
+    assert_eq!(graphics_cursors(&spi), vec![(10, 20)]);
+    assert_eq!(pixel_streams(&spi), vec![vec![0, 1, 0, 2, 0, 3, 0, 4]]);
+}
+
+#[test]
+fn fill_gradient_streams_one_image() {
+    let (mut display, spi) = display((800, 480));
+    let rect = Rectangle::new(Point::new(10, 20), Size::new(3, 2));
+    display
+        .fill_gradient(
+            &rect,
+            0x0000u16,
+            0xFFFFu16,
+            ra8875::GradientDirection::Vertical,
+        )
+        .unwrap();
+    let mut expected = Vec::new();
+    for &color in &[0x0000, 0x0000, 0x0000, 0xFFFF, 0xFFFF, 0xFFFF] {
+        expected.extend_from_slice(&u16::to_be_bytes(color));
+    }
+    assert_eq!(graphics_cursors(&spi), vec![(10, 20)]);
+    assert_eq!(pixel_streams(&spi), vec![expected]);
+
+    spi.clear();
+    display
+        .fill_gradient(
+            &rect,
+            0x0000u16,
+            0xF800u16,
+            ra8875::GradientDirection::Horizontal,
+        )
+        .unwrap();
+    let middle = ra8875::blend_rgb565(0x0000, 0xF800, 127);
+    let mut expected = Vec::new();
+    for &color in &[0x0000, middle, 0xF800, 0x0000, middle, 0xF800] {
+        expected.extend_from_slice(&u16::to_be_bytes(color));
+    }
+    assert_eq!(pixel_streams(&spi), vec![expected]);
+}
+
+#[test]
+fn fill_gradient_clips_to_the_screen() {
+    let (mut display, spi) = display((800, 480));
+    // Only the first two of five columns are on screen, and keep their place in the ramp.
+    let rect = Rectangle::new(Point::new(798, 0), Size::new(5, 1));
+    display
+        .fill_gradient(
+            &rect,
+            0x0000u16,
+            0xF800u16,
+            ra8875::GradientDirection::Horizontal,
+        )
+        .unwrap();
+    let mut expected = Vec::new();
+    for &color in &[0x0000, ra8875::blend_rgb565(0x0000, 0xF800, 63)] {
+        expected.extend_from_slice(&u16::to_be_bytes(color));
+    }
+    assert_eq!(graphics_cursors(&spi), vec![(798, 0)]);
+    assert_eq!(pixel_streams(&spi), vec![expected]);
+
+    // A rectangle too wide for a u16 is cut down to the screen rather than truncated.
+    spi.clear();
+    let rect = Rectangle::new(Point::new(0, 0), Size::new(70_000, 1));
+    display
+        .fill_gradient(
+            &rect,
+            0x0000u16,
+            0xF800u16,
+            ra8875::GradientDirection::Horizontal,
+        )
+        .unwrap();
+    let streams = pixel_streams(&spi);
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams[0].len(), 800 * 2);
+}
+
+#[test]
+fn draw_image_restores_active_window() {
+    let (mut display, spi) = display((800, 480));
+    display.set_active_window((100, 50), (199, 149)).unwrap();
+    display.draw_image((0, 0), (1, 1), &[0xFFFF]).unwrap();
+
+    let window: Vec<_> = spi
+        .register_writes()
+        .into_iter()
+        .filter(|&(register, _)| (0x30..=0x37).contains(&register))
+        .collect();
+    assert_eq!(
+        &window[window.len() - 8..],
+        &[
+            (0x30, 100),
+            (0x31, 0),
+            (0x34, 199),
+            (0x35, 0),
+            (0x32, 50),
+            (0x33, 0),
+            (0x36, 149),
+            (0x37, 0),
+        ]
+    );
+}
+
+#[test]
+fn draw_image_leaves_text_mode_for_the_write() {
+    let (mut display, spi) = display((800, 480));
+    display.text_mode().unwrap();
+    spi.clear();
+    display.draw_image((5, 6), (1, 1), &[0xFFFF]).unwrap();
+
+    let writes = spi.register_writes();
+    // The pixel goes to the graphics cursor, never the text cursor.
+    assert_eq!(graphics_cursors(&spi), vec![(5, 6)]);
+    assert!(writes.iter().all(|&(register, _)| register != 0x2A));
+    // MWCR0 bit 7 is cleared for graphics mode, then set again for text mode.
+    let mwcr0: Vec<_> = writes
+        .iter()
+        .filter(|&&(register, _)| register == 0x40)
+        .map(|&(_, value)| value & 0x80)
+        .collect();
+    assert_eq!(mwcr0.first(), Some(&0x00));
+    assert_eq!(mwcr0.last(), Some(&0x80));
+}
+
+#[test]
+fn draw_image_transparent_rotated_uses_graphics_cursor() {
+    let (mut display, spi) = display((800, 480));
+    display.set_rotation(ra8875::Rotation::Rotate90).unwrap();
+    display.text_mode().unwrap();
+    spi.clear();
+    display
+        .draw_image_transparent((10, 20), 3, &[1, 0, 2], 0u16)
+        .unwrap();
+
+    let writes = spi.register_writes();
+    // Rotated (10, 20) and (12, 20) are hardware (779, 10) and (779, 12).
+    assert_eq!(graphics_cursors(&spi), vec![(779, 10), (779, 12)]);
+    assert!(writes.iter().all(|&(register, _)| register != 0x2A));
+    assert_eq!(spi.last_write(0x40).map(|value| value & 0x80), Some(0x80));
+}
+
+#[cfg(feature = "checked-coords")]
+#[test]
+fn draw_image_restores_window_and_mode_on_error() {
+    let (mut display, spi) = display((800, 480));
+    display.set_rotation(ra8875::Rotation::Rotate90).unwrap();
+    display.text_mode().unwrap();
+    spi.clear();
+    // The first row lands on the panel's last column, the second runs off it.
+    match display.draw_image((0, 799), (1, 2), &[1, 2]) {
+        Err(ra8875::Error::OutOfBounds) => {}
+        _ => panic!("expected OutOfBounds"),
+    }
+
+    let window: Vec<_> = spi
+        .register_writes()
+        .into_iter()
+        .filter(|&(register, _)| (0x30..=0x37).contains(&register))
+        .collect();
+    assert_eq!(
+        &window[window.len() - 8..],
+        &[
+            (0x30, 0),
+            (0x31, 0),
+            (0x34, 0x1F),
+            (0x35, 3),
+            (0x32, 0),
+            (0x33, 0),
+            (0x36, 0xDF),
+            (0x37, 1),
+        ]
+    );
+    assert_eq!(spi.last_write(0x40).map(|value| value & 0x80), Some(0x80));
+}
+
+#[cfg(feature = "checked-coords")]
+#[test]
+fn draw_image_rejects_off_panel_start_untouched() {
+    let (mut display, spi) = display((800, 480));
+    spi.clear();
+    match display.draw_image((800, 0), (1, 1), &[1]) {
+        Err(ra8875::Error::OutOfBounds) => {}
+        _ => panic!("expected OutOfBounds"),
+    }
+    assert!(spi.frames().is_empty());
+}
+
+#[test]
+fn fill_contiguous_rotated_180_seeks_each_row() {
+    let (mut display, spi) = display((800, 480));
+    display.set_rotation(ra8875::Rotation::Rotate180).unwrap();
+    spi.clear();
+    let colors = [1, 2, 3, 4].iter().map(|&c| Rgb565::from(RawU16::new(c)));
+    display
+        .fill_contiguous(&Rectangle::new(Point::new(0, 0), Size::new(2, 2)), colors)
+        .unwrap();
+
+    assert_eq!(graphics_cursors(&spi), vec![(799, 479), (799, 478)]);
+    assert_eq!(
+        pixel_streams(&spi),
+        vec![vec![0, 1, 0, 2], vec![0, 3, 0, 4]]
+    );
+}
+
+#[cfg(feature = "framebuffer")]
+#[test]
+fn framebuffer_flush_rotated_90_seeks_each_row() {
+    use embedded_graphics::pixelcolor::IntoStorage;
+    use ra8875::Framebuffer;
+
+    let (mut display, spi) = display((800, 480));
+    display.set_rotation(ra8875::Rotation::Rotate90).unwrap();
+    let mut frame: Framebuffer<4, 4> = Framebuffer::new();
+    frame
+        .fill_solid(
+            &Rectangle::new(Point::new(1, 1), Size::new(2, 2)),
+            Rgb565::WHITE,
+        )
+        .unwrap();
+    spi.clear();
+    frame.flush(&mut display).unwrap();
+
+    assert_eq!(graphics_cursors(&spi), vec![(798, 1), (797, 1)]);
+    let white = Rgb565::WHITE.into_storage().to_be_bytes();
+    let row: Vec<u8> = white.iter().chain(white.iter()).copied().collect();
+    assert_eq!(pixel_streams(&spi), vec![row.clone(), row]);
+    assert!(!frame.is_dirty());
+}
+
+#[test]
+fn rectangle_in_rotated_90_target_is_transposed() {
+    use embedded_graphics::primitives::{Primitive, PrimitiveStyle};
+
+    let (mut display, spi) = display((800, 480));
+    display.set_rotation(ra8875::Rotation::Rotate90).unwrap();
+    assert_eq!(display.size(), Size::new(480, 800));
+    spi.clear();
+    Rectangle::new(Point::new(10, 20), Size::new(30, 40))
+        .into_styled(PrimitiveStyle::with_fill(Rgb565::WHITE))
+        .draw(&mut display)
+        .unwrap();
+
+    // Rotated x runs down the panel and rotated y runs right to left, so (10, 20)..(39, 59)
+    // covers hardware columns 799 - 59 ..= 799 - 20 and rows 10 ..= 39.
+    let writes = spi.register_writes();
+    assert_eq!(&writes[..8], &shape_corners((740, 10), (779, 39))[..]);
+    assert_eq!(writes.last(), Some(&(0x90, 0xB0)));
+}
+
+#[test]
+fn fill_screen_black_clears_the_full_window() {
+    let (mut display, spi) = display((800, 480));
+    display.set_active_window((100, 50), (199, 149)).unwrap();
+    spi.clear();
+    display.fill_screen(0u16).unwrap();
+
+    // MCLR without the active-window bit, with the background zeroed first.
+    let writes = spi.register_writes();
+    assert_eq!(writes, vec![(0x60, 0), (0x61, 0), (0x62, 0), (0x8E, 0x80)]);
+}
+
+/// What an `ExclusiveSpi` did to its bus and chip-select pin, in order.
+#[derive(Debug, PartialEq)]
+enum BusEvent {
+    Select,
+    Deselect,
+    Write(usize),
+    Flush,
+}
+
+#[derive(Clone, Default)]
+struct BusLog(Rc<RefCell<Vec<BusEvent>>>);
+
+impl spi::ErrorType for BusLog {
+    type Error = Infallible;
+}
+
+impl spi::SpiBus for BusLog {
+    fn read(&mut self, _words: &mut [u8]) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Infallible> {
+        self.0.borrow_mut().push(BusEvent::Write(words.len()));
+        Ok(())
+    }
+
+    fn transfer(&mut self, _read: &mut [u8], _write: &[u8]) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    fn transfer_in_place(&mut self, _words: &mut [u8]) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Infallible> {
+        self.0.borrow_mut().push(BusEvent::Flush);
+        Ok(())
+    }
+}
+
+impl digital::ErrorType for BusLog {
+    type Error = Infallible;
+}
+
+impl digital::OutputPin for BusLog {
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        self.0.borrow_mut().push(BusEvent::Select);
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        self.0.borrow_mut().push(BusEvent::Deselect);
+        Ok(())
+    }
+}
+
+#[test]
+fn exclusive_spi_holds_chip_select_for_a_stream() {
+    let log = BusLog::default();
+    let spi = ExclusiveSpi::new(log.clone(), log.clone());
+    let mut display = RA8875::new(spi, (800, 480), MockPin::high(), MockPin::high());
+    display.push_pixels(100, 0xFFFFu16).unwrap();
+
+    // The MRWC command cycle, then 200 pixel bytes and the data-write prefix in 64 byte
+    // writes under a single chip-select.
+    assert_eq!(
+        *log.0.borrow(),
+        vec![
+            BusEvent::Select,
+            BusEvent::Write(2),
+            BusEvent::Flush,
+            BusEvent::Deselect,
+            BusEvent::Select,
+            BusEvent::Write(64),
+            BusEvent::Write(64),
+            BusEvent::Write(64),
+            BusEvent::Write(9),
+            BusEvent::Flush,
+            BusEvent::Deselect,
+        ]
+    );
+}
@@ -0,0 +1,59 @@
+//! Checks the touch calibration math, which doesn't touch the hardware.
+
+extern crate embedded_graphics;
+extern crate ra8875;
+
+use embedded_graphics::prelude::{Point, Size};
+use ra8875::TouchCalibration;
+
+/// A raw reading for a screen position, on a panel that is offset, scaled on y, and skewed
+/// so that x readings also grow with y.
+fn raw(screen: Point) -> Point {
+    Point::new(screen.x + screen.y + 100, 2 * screen.y + 40)
+}
+
+#[test]
+fn from_points_maps_taps_back_to_targets() {
+    let targets = [
+        Point::new(80, 48),
+        Point::new(720, 240),
+        Point::new(400, 432),
+    ];
+    let taps = [raw(targets[0]), raw(targets[1]), raw(targets[2])];
+    let calibration = TouchCalibration::from_points(targets, taps).unwrap();
+
+    for (&target, &tap) in targets.iter().zip(taps.iter()) {
+        assert_eq!(calibration.apply(tap), target);
+    }
+    // Points other than the taps follow the same transform.
+    let center = Point::new(400, 240);
+    assert_eq!(calibration.apply(raw(center)), center);
+}
+
+#[test]
+fn from_points_rejects_collinear_taps() {
+    let targets = [
+        Point::new(80, 48),
+        Point::new(400, 240),
+        Point::new(720, 432),
+    ];
+    let taps = [
+        Point::new(100, 100),
+        Point::new(200, 200),
+        Point::new(300, 300),
+    ];
+    assert_eq!(TouchCalibration::from_points(targets, taps), None);
+}
+
+#[test]
+fn from_bounds_maps_corners_to_screen_corners() {
+    let (min, max) = (Point::new(100, 200), Point::new(900, 800));
+    let calibration = TouchCalibration::from_bounds(min, max, Size::new(800, 480)).unwrap();
+
+    assert_eq!(calibration.apply(min), Point::new(0, 0));
+    assert_eq!(calibration.apply(max), Point::new(799, 479));
+    assert_eq!(
+        TouchCalibration::from_bounds(min, Point::new(100, 800), Size::new(800, 480)),
+        None
+    );
+}